@@ -0,0 +1,388 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Secret32Bytes, Utilities, UtilitiesError, UtilitiesResult,
+};
+use chacha20poly1305::{
+    aead::{generic_array::GenericArray, AeadInPlace, KeyInit},
+    ChaCha20Poly1305, XChaCha20Poly1305, XChaCha8Poly1305,
+};
+
+#[cfg(feature = "aes_gcm")]
+use aes_gcm::Aes256Gcm;
+
+impl Utilities {
+    /// Encrypt bytes using `XChaCha20Poly1305` returning the ciphertext
+    /// with the 16 byte authentication tag appended
+    pub fn xchacha20poly1305_encrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        plaintext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_encrypt::<XChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            plaintext,
+            associated_data,
+            UtilitiesError::XChaCha20Poly1305EncryptionError,
+        )
+    }
+
+    /// Decrypt a ciphertext, with the 16 byte authentication tag appended,
+    /// that was encrypted using `XChaCha20Poly1305`
+    pub fn xchacha20poly1305_decrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_decrypt::<XChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            ciphertext,
+            associated_data,
+            UtilitiesError::XChaCha20Poly1305DecryptionError,
+        )
+    }
+
+    /// Encrypt the bytes in the buffer in place using `XChaCha20Poly1305`
+    /// returning the authentication tag
+    pub fn xchacha20poly1305_encrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<AeadTag> {
+        Utilities::aead_encrypt_detached::<XChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            UtilitiesError::XChaCha20Poly1305EncryptionError,
+        )
+    }
+
+    /// Decrypt the bytes in the buffer in place using `XChaCha20Poly1305`
+    /// after checking the authentication tag in constant time
+    pub fn xchacha20poly1305_decrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+        tag: &AeadTag,
+    ) -> UtilitiesResult<()> {
+        Utilities::aead_decrypt_detached::<XChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            tag,
+            UtilitiesError::XChaCha20Poly1305DecryptionError,
+        )
+    }
+
+    /// Encrypt bytes using `XChaCha8Poly1305` returning the ciphertext
+    /// with the 16 byte authentication tag appended
+    pub fn xchacha8poly1305_encrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        plaintext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_encrypt::<XChaCha8Poly1305>(
+            key,
+            &nonce.0,
+            plaintext,
+            associated_data,
+            UtilitiesError::XChaCha8Poly1305EncryptionError,
+        )
+    }
+
+    /// Decrypt a ciphertext, with the 16 byte authentication tag appended,
+    /// that was encrypted using `XChaCha8Poly1305`
+    pub fn xchacha8poly1305_decrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_decrypt::<XChaCha8Poly1305>(
+            key,
+            &nonce.0,
+            ciphertext,
+            associated_data,
+            UtilitiesError::XChaCha8Poly1305DecryptionError,
+        )
+    }
+
+    /// Encrypt the bytes in the buffer in place using `XChaCha8Poly1305`
+    /// returning the authentication tag
+    pub fn xchacha8poly1305_encrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<AeadTag> {
+        Utilities::aead_encrypt_detached::<XChaCha8Poly1305>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            UtilitiesError::XChaCha8Poly1305EncryptionError,
+        )
+    }
+
+    /// Decrypt the bytes in the buffer in place using `XChaCha8Poly1305`
+    /// after checking the authentication tag in constant time
+    pub fn xchacha8poly1305_decrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadXNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+        tag: &AeadTag,
+    ) -> UtilitiesResult<()> {
+        Utilities::aead_decrypt_detached::<XChaCha8Poly1305>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            tag,
+            UtilitiesError::XChaCha8Poly1305DecryptionError,
+        )
+    }
+
+    /// Encrypt bytes using `ChaCha20Poly1305` returning the ciphertext
+    /// with the 16 byte authentication tag appended
+    pub fn chacha20poly1305_encrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        plaintext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_encrypt::<ChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            plaintext,
+            associated_data,
+            UtilitiesError::ChaCha20Poly1305EncryptionError,
+        )
+    }
+
+    /// Decrypt a ciphertext, with the 16 byte authentication tag appended,
+    /// that was encrypted using `ChaCha20Poly1305`
+    pub fn chacha20poly1305_decrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_decrypt::<ChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            ciphertext,
+            associated_data,
+            UtilitiesError::ChaCha20Poly1305DecryptionError,
+        )
+    }
+
+    /// Encrypt the bytes in the buffer in place using `ChaCha20Poly1305`
+    /// returning the authentication tag
+    pub fn chacha20poly1305_encrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<AeadTag> {
+        Utilities::aead_encrypt_detached::<ChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            UtilitiesError::ChaCha20Poly1305EncryptionError,
+        )
+    }
+
+    /// Decrypt the bytes in the buffer in place using `ChaCha20Poly1305`
+    /// after checking the authentication tag in constant time
+    pub fn chacha20poly1305_decrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+        tag: &AeadTag,
+    ) -> UtilitiesResult<()> {
+        Utilities::aead_decrypt_detached::<ChaCha20Poly1305>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            tag,
+            UtilitiesError::ChaCha20Poly1305DecryptionError,
+        )
+    }
+
+    /// Encrypt bytes using `Aes256Gcm` returning the ciphertext
+    /// with the 16 byte authentication tag appended
+    #[cfg(feature = "aes_gcm")]
+    pub fn aes256gcm_encrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        plaintext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_encrypt::<Aes256Gcm>(
+            key,
+            &nonce.0,
+            plaintext,
+            associated_data,
+            UtilitiesError::Aes256GcmEncryptionError,
+        )
+    }
+
+    /// Decrypt a ciphertext, with the 16 byte authentication tag appended,
+    /// that was encrypted using `Aes256Gcm`
+    #[cfg(feature = "aes_gcm")]
+    pub fn aes256gcm_decrypt(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<Vec<u8>> {
+        Utilities::aead_decrypt::<Aes256Gcm>(
+            key,
+            &nonce.0,
+            ciphertext,
+            associated_data,
+            UtilitiesError::Aes256GcmDecryptionError,
+        )
+    }
+
+    /// Encrypt the bytes in the buffer in place using `Aes256Gcm`
+    /// returning the authentication tag
+    #[cfg(feature = "aes_gcm")]
+    pub fn aes256gcm_encrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+    ) -> UtilitiesResult<AeadTag> {
+        Utilities::aead_encrypt_detached::<Aes256Gcm>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            UtilitiesError::Aes256GcmEncryptionError,
+        )
+    }
+
+    /// Decrypt the bytes in the buffer in place using `Aes256Gcm`
+    /// after checking the authentication tag in constant time
+    #[cfg(feature = "aes_gcm")]
+    pub fn aes256gcm_decrypt_detached(
+        key: &Secret32Bytes,
+        nonce: &AeadNonce,
+        buffer: &mut [u8],
+        associated_data: &[u8],
+        tag: &AeadTag,
+    ) -> UtilitiesResult<()> {
+        Utilities::aead_decrypt_detached::<Aes256Gcm>(
+            key,
+            &nonce.0,
+            buffer,
+            associated_data,
+            tag,
+            UtilitiesError::Aes256GcmDecryptionError,
+        )
+    }
+
+    fn aead_encrypt<C: AeadInPlace + KeyInit>(
+        key: &Secret32Bytes,
+        nonce: &[u8],
+        plaintext: &[u8],
+        associated_data: &[u8],
+        error: UtilitiesError,
+    ) -> UtilitiesResult<Vec<u8>> {
+        let mut ciphertext = plaintext.to_vec();
+        let tag = Utilities::aead_encrypt_detached::<C>(
+            key,
+            nonce,
+            &mut ciphertext,
+            associated_data,
+            error,
+        )?;
+        ciphertext.extend_from_slice(&tag.0);
+
+        Ok(ciphertext)
+    }
+
+    fn aead_decrypt<C: AeadInPlace + KeyInit>(
+        key: &Secret32Bytes,
+        nonce: &[u8],
+        ciphertext: &[u8],
+        associated_data: &[u8],
+        error: UtilitiesError,
+    ) -> UtilitiesResult<Vec<u8>> {
+        let tag_index = match ciphertext.len().checked_sub(16) {
+            Some(tag_index) => tag_index,
+            None => return Err(error),
+        };
+        let tag = AeadTag(Utilities::to_byte_array(&ciphertext[tag_index..])?);
+        let mut plaintext = ciphertext[..tag_index].to_vec();
+        Utilities::aead_decrypt_detached::<C>(
+            key,
+            nonce,
+            &mut plaintext,
+            associated_data,
+            &tag,
+            error,
+        )?;
+
+        Ok(plaintext)
+    }
+
+    fn aead_encrypt_detached<C: AeadInPlace + KeyInit>(
+        key: &Secret32Bytes,
+        nonce: &[u8],
+        buffer: &mut [u8],
+        associated_data: &[u8],
+        error: UtilitiesError,
+    ) -> UtilitiesResult<AeadTag> {
+        let cipher = match C::new_from_slice(&key.0) {
+            Ok(cipher) => cipher,
+            Err(_) => return Err(error),
+        };
+
+        match cipher.encrypt_in_place_detached(
+            GenericArray::from_slice(nonce),
+            associated_data,
+            buffer,
+        ) {
+            Ok(tag) => Ok(AeadTag(Utilities::to_byte_array(&tag)?)),
+            Err(_) => Err(error),
+        }
+    }
+
+    fn aead_decrypt_detached<C: AeadInPlace + KeyInit>(
+        key: &Secret32Bytes,
+        nonce: &[u8],
+        buffer: &mut [u8],
+        associated_data: &[u8],
+        tag: &AeadTag,
+        error: UtilitiesError,
+    ) -> UtilitiesResult<()> {
+        let cipher = match C::new_from_slice(&key.0) {
+            Ok(cipher) => cipher,
+            Err(_) => return Err(error),
+        };
+
+        // The authentication tag is checked in constant time by `AeadInPlace`
+        match cipher.decrypt_in_place_detached(
+            GenericArray::from_slice(nonce),
+            associated_data,
+            buffer,
+            GenericArray::from_slice(&tag.0),
+        ) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(error),
+        }
+    }
+}
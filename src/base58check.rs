@@ -0,0 +1,106 @@
+use crate::{Sha256Hash, Utilities, UtilitiesError, UtilitiesResult};
+
+#[cfg(feature = "tezos")]
+use crate::{Ed25519Public, Ed25519Signature};
+#[cfg(feature = "tezos")]
+use blake2::{digest::consts::U20, Blake2b, Digest};
+
+/// The number of bytes of the double SHA-256 checksum appended to the payload
+pub const BASE58CHECK_CHECKSUM_LENGTH: usize = 4;
+
+/// Bitcoin mainnet Pay-to-PubkeyHash address prefix, encodes to an address starting with `1`
+pub const BITCOIN_P2PKH_PREFIX: [u8; 1] = [0x00];
+/// Bitcoin mainnet Pay-to-ScriptHash address prefix, encodes to an address starting with `3`
+pub const BITCOIN_P2SH_PREFIX: [u8; 1] = [0x05];
+/// Bitcoin mainnet Wallet Import Format secret key prefix
+pub const BITCOIN_WIF_PREFIX: [u8; 1] = [0x80];
+/// Bitcoin testnet Pay-to-PubkeyHash address prefix, encodes to an address starting with `m` or `n`
+pub const BITCOIN_TESTNET_P2PKH_PREFIX: [u8; 1] = [0x6f];
+/// Bitcoin testnet Pay-to-ScriptHash address prefix, encodes to an address starting with `2`
+pub const BITCOIN_TESTNET_P2SH_PREFIX: [u8; 1] = [0xc4];
+/// Bitcoin testnet Wallet Import Format secret key prefix
+pub const BITCOIN_TESTNET_WIF_PREFIX: [u8; 1] = [0xef];
+/// Tezos Ed25519 public key prefix, encodes to a string starting with `edpk`
+pub const TEZOS_ED25519_PUBLIC_KEY_PREFIX: [u8; 4] = [13, 15, 37, 217];
+/// Tezos Ed25519 signature prefix, encodes to a string starting with `edsig`
+pub const TEZOS_ED25519_SIGNATURE_PREFIX: [u8; 5] = [9, 245, 205, 134, 18];
+/// Tezos Ed25519 public key hash prefix, encodes to an address starting with `tz1`
+pub const TEZOS_ED25519_PUBLIC_KEY_HASH_PREFIX: [u8; 3] = [6, 161, 159];
+/// Tezos Ed25519 secret key seed prefix, encodes to a string starting with `edsk`
+pub const TEZOS_ED25519_SEED_PREFIX: [u8; 4] = [13, 15, 58, 7];
+
+impl Utilities {
+    /// Encode some bytes using Base58Check, which is the `prefix` followed by the bytes
+    /// and the first 4 bytes of `SHA256(SHA256(prefix || bytes))`
+    pub fn to_base58check(prefix: &[u8], bytes: &[u8]) -> String {
+        let mut payload = [prefix, bytes].concat();
+        let checksum = Sha256Hash::double_hash(&payload);
+        payload.extend_from_slice(&checksum.0[..BASE58CHECK_CHECKSUM_LENGTH]);
+
+        bs58::encode(payload).into_string()
+    }
+
+    /// Decode a Base58Check string returning the bytes without the prefix and checksum.
+    /// Returns `UtilitiesError::Base58CheckInvalidChecksum` if the checksum does not match
+    /// and `UtilitiesError::Base58CheckInvalidPrefix` if the string does not start with
+    /// the `expected_prefix`
+    pub fn from_base58check(expected_prefix: &[u8], value: &str) -> UtilitiesResult<Vec<u8>> {
+        let mut payload = Utilities::base58_to_bytes(value)?;
+        let minimum_length = expected_prefix.len() + BASE58CHECK_CHECKSUM_LENGTH;
+        if payload.len() < minimum_length {
+            return Err(UtilitiesError::InvalidLength {
+                expected: minimum_length,
+                actual: payload.len(),
+            });
+        }
+
+        let checksum = payload.split_off(payload.len() - BASE58CHECK_CHECKSUM_LENGTH);
+        if Sha256Hash::double_hash(&payload).0[..BASE58CHECK_CHECKSUM_LENGTH] != checksum[..] {
+            return Err(UtilitiesError::Base58CheckInvalidChecksum);
+        }
+
+        match payload.starts_with(expected_prefix) {
+            true => Ok(payload.split_off(expected_prefix.len())),
+            false => Err(UtilitiesError::Base58CheckInvalidPrefix),
+        }
+    }
+}
+
+#[cfg(feature = "tezos")]
+impl Ed25519Public {
+    /// Return the Tezos representation of the public key starting with `edpk`
+    pub fn to_tezos(&self) -> String {
+        Utilities::to_base58check(&TEZOS_ED25519_PUBLIC_KEY_PREFIX, &self.0)
+    }
+
+    /// Decode a Tezos public key starting with `edpk`
+    pub fn from_tezos(value: &str) -> UtilitiesResult<Self> {
+        Ed25519Public::try_from(
+            &Utilities::from_base58check(&TEZOS_ED25519_PUBLIC_KEY_PREFIX, value)?[..],
+        )
+    }
+
+    /// Return the Tezos address of the public key starting with `tz1`,
+    /// which is the Base58Check of the 20 byte Blake2b hash of the public key
+    pub fn to_tezos_address(&self) -> String {
+        Utilities::to_base58check(
+            &TEZOS_ED25519_PUBLIC_KEY_HASH_PREFIX,
+            &Blake2b::<U20>::digest(self.0),
+        )
+    }
+}
+
+#[cfg(feature = "tezos")]
+impl Ed25519Signature {
+    /// Return the Tezos representation of the signature starting with `edsig`
+    pub fn to_tezos(&self) -> String {
+        Utilities::to_base58check(&TEZOS_ED25519_SIGNATURE_PREFIX, &self.0)
+    }
+
+    /// Decode a Tezos signature starting with `edsig`
+    pub fn from_tezos(value: &str) -> UtilitiesResult<Self> {
+        Ed25519Signature::try_from(
+            &Utilities::from_base58check(&TEZOS_ED25519_SIGNATURE_PREFIX, value)?[..],
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Ed25519Public, Sha256Hash, Sr25519Public, Utilities, UtilitiesError, UtilitiesResult};
+use bech32::{FromBase32, ToBase32, Variant};
+use ripemd::{Digest, Ripemd160};
+
+/// The maximum length of a Bech32 or Bech32m string as specified in BIP-173
+pub const BECH32_MAX_LENGTH: usize = 90;
+/// The human readable part of Cosmos Hub account addresses
+pub const COSMOS_ACCOUNT_HRP: &str = "cosmos";
+
+/// The checksum algorithm of a Bech32 string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bech32Variant {
+    /// The original checksum specified in BIP-173, used by Cosmos addresses
+    Bech32,
+    /// The improved checksum specified in BIP-350
+    Bech32m,
+}
+
+impl From<Bech32Variant> for Variant {
+    fn from(variant: Bech32Variant) -> Self {
+        match variant {
+            Bech32Variant::Bech32 => Variant::Bech32,
+            Bech32Variant::Bech32m => Variant::Bech32m,
+        }
+    }
+}
+
+impl From<Variant> for Bech32Variant {
+    fn from(variant: Variant) -> Self {
+        match variant {
+            Variant::Bech32 => Bech32Variant::Bech32,
+            Variant::Bech32m => Bech32Variant::Bech32m,
+        }
+    }
+}
+
+impl Utilities {
+    /// Encode some bytes as a Bech32 or Bech32m string with the human readable part `hrp`.
+    /// Returns `UtilitiesError::Bech32InvalidLength` if the string would be longer than 90 characters
+    pub fn to_bech32(hrp: &str, bytes: &[u8], variant: Bech32Variant) -> UtilitiesResult<String> {
+        let encoded = bech32::encode(hrp, bytes.to_base32(), variant.into())?;
+
+        match encoded.len() > BECH32_MAX_LENGTH {
+            true => Err(UtilitiesError::Bech32InvalidLength),
+            false => Ok(encoded),
+        }
+    }
+
+    /// Decode a Bech32 or Bech32m string returning its human readable part in lowercase,
+    /// the bytes and the checksum variant
+    pub fn decode_bech32(value: &str) -> UtilitiesResult<(String, Vec<u8>, Bech32Variant)> {
+        if value.len() > BECH32_MAX_LENGTH {
+            return Err(UtilitiesError::Bech32InvalidLength);
+        }
+
+        let (hrp, data, variant) = bech32::decode(value)?;
+
+        Ok((hrp, Vec::<u8>::from_base32(&data)?, variant.into()))
+    }
+
+    /// Decode a Bech32 or Bech32m string checking that it has the expected
+    /// human readable part and checksum variant
+    pub fn from_bech32(
+        expected_hrp: &str,
+        expected_variant: Bech32Variant,
+        value: &str,
+    ) -> UtilitiesResult<Vec<u8>> {
+        let (hrp, bytes, variant) = Utilities::decode_bech32(value)?;
+
+        if hrp != expected_hrp.to_lowercase() {
+            Err(UtilitiesError::Bech32InvalidHrp)
+        } else if variant != expected_variant {
+            Err(UtilitiesError::Bech32InvalidVariant)
+        } else {
+            Ok(bytes)
+        }
+    }
+
+    /// Derive the 20 byte Cosmos account id of a public key as `RIPEMD160(SHA256(public_key))`
+    pub fn cosmos_account_id(public_key: &[u8]) -> [u8; 20] {
+        Ripemd160::digest(Sha256Hash::hash(public_key).0).into()
+    }
+
+    /// Derive the Cosmos style Bech32 address of a public key with the human readable part `hrp`,
+    /// for example `cosmos1…` when the `hrp` is `COSMOS_ACCOUNT_HRP`
+    pub fn to_cosmos_address(hrp: &str, public_key: &[u8]) -> UtilitiesResult<String> {
+        Utilities::to_bech32(
+            hrp,
+            &Utilities::cosmos_account_id(public_key),
+            Bech32Variant::Bech32,
+        )
+    }
+}
+
+impl Ed25519Public {
+    /// Encode the public key as a Bech32 or Bech32m string with the human readable part `hrp`
+    pub fn to_bech32(&self, hrp: &str, variant: Bech32Variant) -> UtilitiesResult<String> {
+        Utilities::to_bech32(hrp, &self.0, variant)
+    }
+
+    /// Decode the public key from a Bech32 or Bech32m string checking that it has the expected
+    /// human readable part and checksum variant
+    pub fn from_bech32(
+        expected_hrp: &str,
+        expected_variant: Bech32Variant,
+        value: &str,
+    ) -> UtilitiesResult<Self> {
+        Ed25519Public::try_from(&Utilities::from_bech32(expected_hrp, expected_variant, value)?[..])
+    }
+}
+
+impl Sr25519Public {
+    /// Encode the public key as a Bech32 or Bech32m string with the human readable part `hrp`
+    pub fn to_bech32(&self, hrp: &str, variant: Bech32Variant) -> UtilitiesResult<String> {
+        Utilities::to_bech32(hrp, &self.0, variant)
+    }
+
+    /// Decode the public key from a Bech32 or Bech32m string checking that it has the expected
+    /// human readable part and checksum variant
+    pub fn from_bech32(
+        expected_hrp: &str,
+        expected_variant: Bech32Variant,
+        value: &str,
+    ) -> UtilitiesResult<Self> {
+        Sr25519Public::try_from(&Utilities::from_bech32(expected_hrp, expected_variant, value)?[..])
+    }
+}
@@ -81,4 +81,138 @@ impl Utilities {
             Err(_) => Err(UtilitiesError::InvalidBytesForSr25519Signature),
         }
     }
+
+    /// Check if a `schnorrkel::Keypair` was used to sign a message
+    /// under the given signing context, for example Substrate's `b"substrate"`.
+    #[cfg(feature = "sr25519")]
+    pub fn is_signer_sr25519(
+        public_key: &Sr25519PublicKey,
+        context: &[u8],
+        message: &[u8],
+        signature: &Sr25519Signature,
+    ) -> UtilitiesResult<()> {
+        match public_key.verify_simple(context, message, signature) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(UtilitiesError::InvalidSr25519Signature),
+        }
+    }
+
+    /// Verify a batch of Ed25519 signatures using `ed25519_dalek::verify_batch`.
+    /// If the batch fails to verify, each signature is verified individually
+    /// and the indices of the invalid signatures are returned in
+    /// `UtilitiesError::InvalidSignaturesInBatch`
+    #[cfg(all(feature = "ed25519", feature = "batch"))]
+    pub fn verify_batch_ed25519(
+        batch: &[(crate::Ed25519Public, &[u8], crate::Ed25519Signature)],
+    ) -> UtilitiesResult<()> {
+        let parsed = batch
+            .iter()
+            .map(|(public_key, _, signature)| {
+                match (
+                    Utilities::to_ed25519_publickey(&public_key.0),
+                    Utilities::to_ed25519_sig(&signature.0),
+                ) {
+                    (Ok(public_key), Ok(signature)) => Some((public_key, signature)),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<(Ed25519PublicKey, Ed25519Signature)>>>();
+
+        if let Some(parsed) = parsed {
+            let messages = batch
+                .iter()
+                .map(|(_, message, _)| *message)
+                .collect::<Vec<&[u8]>>();
+            let (public_keys, signatures): (Vec<Ed25519PublicKey>, Vec<Ed25519Signature>) =
+                parsed.into_iter().unzip();
+
+            if ed25519_dalek::verify_batch(&messages, &signatures, &public_keys).is_ok() {
+                return Ok(());
+            }
+        }
+
+        let indices = batch
+            .iter()
+            .enumerate()
+            .filter(|(_, (public_key, message, signature))| {
+                match (
+                    Utilities::to_ed25519_publickey(&public_key.0),
+                    Utilities::to_ed25519_sig(&signature.0),
+                ) {
+                    (Ok(public_key), Ok(signature)) => {
+                        Utilities::is_signer_ed25519(&public_key, message, &signature).is_err()
+                    }
+                    _ => true,
+                }
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<usize>>();
+
+        if indices.is_empty() {
+            Ok(())
+        } else {
+            Err(UtilitiesError::InvalidSignaturesInBatch { indices })
+        }
+    }
+
+    /// Verify a batch of Sr25519 signatures signed under the same signing context
+    /// using `schnorrkel::verify_batch`.
+    /// If the batch fails to verify, each signature is verified individually
+    /// and the indices of the invalid signatures are returned in
+    /// `UtilitiesError::InvalidSignaturesInBatch`
+    #[cfg(all(feature = "sr25519", feature = "batch"))]
+    pub fn verify_batch_sr25519(
+        context: &[u8],
+        batch: &[(crate::Sr25519Public, &[u8], crate::Sr25519Signature)],
+    ) -> UtilitiesResult<()> {
+        let parsed = batch
+            .iter()
+            .map(|(public_key, _, signature)| {
+                match (
+                    Utilities::to_sr25519_publickey(&public_key.0),
+                    Utilities::to_sr25519_sig(&signature.0),
+                ) {
+                    (Ok(public_key), Ok(signature)) => Some((public_key, signature)),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<(Sr25519PublicKey, Sr25519Signature)>>>();
+
+        if let Some(parsed) = parsed {
+            let signing_context = schnorrkel::signing_context(context);
+            let transcripts = batch
+                .iter()
+                .map(|(_, message, _)| signing_context.bytes(message));
+            let (public_keys, signatures): (Vec<Sr25519PublicKey>, Vec<Sr25519Signature>) =
+                parsed.into_iter().unzip();
+
+            if schnorrkel::verify_batch(transcripts, &signatures, &public_keys, false).is_ok() {
+                return Ok(());
+            }
+        }
+
+        let indices = batch
+            .iter()
+            .enumerate()
+            .filter(|(_, (public_key, message, signature))| {
+                match (
+                    Utilities::to_sr25519_publickey(&public_key.0),
+                    Utilities::to_sr25519_sig(&signature.0),
+                ) {
+                    (Ok(public_key), Ok(signature)) => {
+                        Utilities::is_signer_sr25519(&public_key, context, message, &signature)
+                            .is_err()
+                    }
+                    _ => true,
+                }
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<usize>>();
+
+        if indices.is_empty() {
+            Ok(())
+        } else {
+            Err(UtilitiesError::InvalidSignaturesInBatch { indices })
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use crate::{Utilities, UtilitiesError, UtilitiesResult};
 use borsh::{BorshDeserialize, BorshSerialize};
 use constant_time_eq::constant_time_eq_n;
 use core::{
@@ -45,7 +46,7 @@ impl Blake3Hash {
     /// String representation of the Blake3 Hash bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 }
 
@@ -76,6 +77,186 @@ impl fmt::Debug for Blake3Hash {
     }
 }
 
+/// A representation of SHA-256 hash byte representation with
+/// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
+/// and an implementation for Borsh encoding that ensure
+/// no two binary representations that deserialize into the same object
+/// and a possibly smaller code size compared to serde binary representations.
+#[derive(Clone, Copy, Default, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub struct Sha256Hash(pub ByteArray32);
+
+impl Sha256Hash {
+    /// String representation of the SHA-256 Hash bytes
+    #[cfg(feature = "hex")]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl Zeroize for Sha256Hash {
+    fn zeroize(&mut self) {
+        self.0 = Sha256Hash::default().0;
+    }
+}
+
+impl PartialEq for Sha256Hash {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl Eq for Sha256Hash {}
+
+impl Hash for Sha256Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Debug for Sha256Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sha256Hash").field(&self.to_hex()).finish()
+    }
+}
+
+/// A representation of SHA-512 hash byte representation with
+/// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
+/// and an implementation for Borsh encoding that ensure
+/// no two binary representations that deserialize into the same object
+/// and a possibly smaller code size compared to serde binary representations.
+#[derive(Clone, Copy, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub struct Sha512Hash(pub ByteArray64);
+
+impl Sha512Hash {
+    /// String representation of the SHA-512 Hash bytes
+    #[cfg(feature = "hex")]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl Default for Sha512Hash {
+    fn default() -> Self {
+        Sha512Hash([0u8; 64])
+    }
+}
+
+impl Zeroize for Sha512Hash {
+    fn zeroize(&mut self) {
+        self.0 = Sha512Hash::default().0;
+    }
+}
+
+impl PartialEq for Sha512Hash {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl Eq for Sha512Hash {}
+
+impl Hash for Sha512Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Debug for Sha512Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sha512Hash").field(&self.to_hex()).finish()
+    }
+}
+
+/// A representation of SHA3-256 hash byte representation with
+/// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
+/// and an implementation for Borsh encoding that ensure
+/// no two binary representations that deserialize into the same object
+/// and a possibly smaller code size compared to serde binary representations.
+#[derive(Clone, Copy, Default, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub struct Sha3_256Hash(pub ByteArray32);
+
+impl Sha3_256Hash {
+    /// String representation of the SHA3-256 Hash bytes
+    #[cfg(feature = "hex")]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl Zeroize for Sha3_256Hash {
+    fn zeroize(&mut self) {
+        self.0 = Sha3_256Hash::default().0;
+    }
+}
+
+impl PartialEq for Sha3_256Hash {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl Eq for Sha3_256Hash {}
+
+impl Hash for Sha3_256Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Debug for Sha3_256Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sha3_256Hash").field(&self.to_hex()).finish()
+    }
+}
+
+/// A representation of Keccak-256 hash byte representation with
+/// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
+/// and an implementation for Borsh encoding that ensure
+/// no two binary representations that deserialize into the same object
+/// and a possibly smaller code size compared to serde binary representations.
+#[derive(Clone, Copy, Default, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub struct Keccak256Hash(pub ByteArray32);
+
+impl Keccak256Hash {
+    /// String representation of the Keccak-256 Hash bytes
+    #[cfg(feature = "hex")]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl Zeroize for Keccak256Hash {
+    fn zeroize(&mut self) {
+        self.0 = Keccak256Hash::default().0;
+    }
+}
+
+impl PartialEq for Keccak256Hash {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl Eq for Keccak256Hash {}
+
+impl Hash for Keccak256Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Debug for Keccak256Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Keccak256Hash")
+            .field(&self.to_hex())
+            .finish()
+    }
+}
+
 /// A representation of 12 byte Tai64N monotonic timestamp byte with
 /// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
 /// and an implementation for Borsh encoding that ensure
@@ -88,7 +269,6 @@ impl TaiTimestamp {
     /// Convert to a human readable data and time as a`String`primitive
     #[cfg(feature = "tai64")]
     pub fn to_datetime(&self) -> crate::UtilitiesResult<String> {
-        use crate::Utilities;
         use monotonic_time::DateTime;
 
         let timestamp = Utilities::bytes_to_tai64n(&self.0)?;
@@ -102,7 +282,7 @@ impl TaiTimestamp {
     /// Return the `hex` representation of the bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 }
 
@@ -169,7 +349,7 @@ impl Ed25519Public {
     /// Return the `hex` representation of the bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 
     /// Return the `base58` representation of the bytes
@@ -221,7 +401,7 @@ impl Ed25519Signature {
     /// Return the `hex` representation of the bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 
     /// Return the `base58` representation of the bytes
@@ -280,7 +460,7 @@ impl Sr25519Public {
     /// Return the `hex` representation of the bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 
     /// Return the `base58` representation of the bytes
@@ -332,7 +512,7 @@ impl Sr25519Signature {
     /// Return the `hex` representation of the bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 
     /// Return the `base58` representation of the bytes
@@ -390,7 +570,7 @@ impl X25519Public {
     /// Return the `hex` representation of the bytes
     #[cfg(feature = "hex")]
     pub fn to_hex(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 
     /// Return the `base58` representation of the bytes
@@ -453,7 +633,13 @@ impl Hash for Secret32Bytes {
 
 impl Zeroize for Secret32Bytes {
     fn zeroize(&mut self) {
-        self.0 = Secret32Bytes::default().0;
+        self.0.zeroize();
+    }
+}
+
+impl Drop for Secret32Bytes {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -464,7 +650,7 @@ impl Secret32Bytes {
     /// Debug the secret key. This is a dangerous operation since
     /// it returns the hex of the secret key which can be logged
     pub fn dangerous_debug(&self) -> String {
-        hex::encode(&self.0)
+        hex::encode(self.0)
     }
 
     /// Return the `hex` representation of the bytes
@@ -519,7 +705,7 @@ impl Hash for AeadNonce {
 impl fmt::Debug for AeadNonce {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("AeadNonce")
-            .field(&hex::encode(&self.0))
+            .field(&hex::encode(self.0))
             .finish()
     }
 }
@@ -557,7 +743,7 @@ impl Hash for AeadXNonce {
 impl fmt::Debug for AeadXNonce {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("AeadXNonce")
-            .field(&hex::encode(&self.0))
+            .field(&hex::encode(self.0))
             .finish()
     }
 }
@@ -595,7 +781,88 @@ impl Hash for AeadTag {
 impl fmt::Debug for AeadTag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("AeadTag")
-            .field(&hex::encode(&self.0))
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
+/// A representation of a 32 byte message authentication code tag, like HMAC-SHA256
+/// and Blake3 keyed hash tags, with
+/// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
+/// and an implementation for Borsh encoding that ensure
+/// no two binary representations that deserialize into the same object
+/// and a possibly smaller code size compared to serde binary representations.
+#[derive(Clone, Default, Copy, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub struct MacTag32(pub ByteArray32);
+
+impl Zeroize for MacTag32 {
+    fn zeroize(&mut self) {
+        self.0 = MacTag32::default().0
+    }
+}
+
+impl PartialEq for MacTag32 {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl Eq for MacTag32 {}
+
+impl Hash for MacTag32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Debug for MacTag32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MacTag32")
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
+/// A representation of a 64 byte message authentication code tag, like HMAC-SHA512 tags, with
+/// default constant time equality checks, hex `fmt::Debug` and hex `fmt::Display`,
+/// and an implementation for Borsh encoding that ensure
+/// no two binary representations that deserialize into the same object
+/// and a possibly smaller code size compared to serde binary representations.
+#[derive(Clone, Copy, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub struct MacTag64(pub ByteArray64);
+
+impl Default for MacTag64 {
+    fn default() -> Self {
+        MacTag64([0u8; 64])
+    }
+}
+
+impl Zeroize for MacTag64 {
+    fn zeroize(&mut self) {
+        self.0 = MacTag64::default().0
+    }
+}
+
+impl PartialEq for MacTag64 {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl Eq for MacTag64 {}
+
+impl Hash for MacTag64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Debug for MacTag64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MacTag64")
+            .field(&hex::encode(self.0))
             .finish()
     }
 }
@@ -625,7 +892,13 @@ impl Hash for SecretVec {
 
 impl Zeroize for SecretVec {
     fn zeroize(&mut self) {
-        self.0.clear();
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretVec {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -677,3 +950,51 @@ impl fmt::Debug for HexVec {
             .finish()
     }
 }
+
+impl From<&[u8]> for HexVec {
+    fn from(bytes: &[u8]) -> Self {
+        HexVec(bytes.to_vec())
+    }
+}
+
+impl From<&[u8]> for SecretVec {
+    fn from(bytes: &[u8]) -> Self {
+        SecretVec(bytes.to_vec())
+    }
+}
+
+/// Implements `TryFrom<&[u8]>` for the fixed size data types returning
+/// `UtilitiesError::InvalidLength` if the length of the bytes is invalid
+macro_rules! impl_try_from_slice {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl TryFrom<&[u8]> for $data_type {
+                type Error = UtilitiesError;
+
+                fn try_from(bytes: &[u8]) -> UtilitiesResult<Self> {
+                    Ok($data_type(Utilities::to_byte_array(bytes)?))
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_slice!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    Secret32Bytes,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+);
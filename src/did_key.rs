@@ -0,0 +1,113 @@
+use crate::{
+    Ed25519Public, Sr25519Public, Utilities, UtilitiesError, UtilitiesResult, X25519Public,
+};
+
+/// The multicodec code of an Ed25519 public key, `ed25519-pub`
+pub const MULTICODEC_ED25519_PUB: u64 = 0xed;
+/// The multicodec code of an X25519 public key, `x25519-pub`
+pub const MULTICODEC_X25519_PUB: u64 = 0xec;
+/// The multicodec code of an Sr25519 public key, `sr25519-pub`
+pub const MULTICODEC_SR25519_PUB: u64 = 0xef;
+/// The prefix of a `did:key` identifier including the `z` base58btc multibase prefix
+pub const DID_KEY_PREFIX: &str = "did:key:z";
+
+/// The maximum number of bytes of a multiformats unsigned varint
+pub const MULTICODEC_MAX_VARINT_LENGTH: usize = 9;
+/// The largest multicodec code, the largest value of 63 bits that fits in a 9 byte unsigned varint
+pub const MULTICODEC_MAX_CODE: u64 = (1 << 63) - 1;
+
+impl Utilities {
+    /// Prefix the bytes with the multicodec code encoded as an unsigned varint.
+    /// Returns `UtilitiesError::MulticodecInvalidVarint` if the code is greater than `MULTICODEC_MAX_CODE`
+    pub fn to_multicodec(code: u64, bytes: &[u8]) -> UtilitiesResult<Vec<u8>> {
+        if code > MULTICODEC_MAX_CODE {
+            return Err(UtilitiesError::MulticodecInvalidVarint);
+        }
+
+        let mut encoded = Vec::with_capacity(MULTICODEC_MAX_VARINT_LENGTH + bytes.len());
+        let mut value = code;
+
+        while value >= 0x80 {
+            encoded.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        encoded.push(value as u8);
+        encoded.extend_from_slice(bytes);
+
+        Ok(encoded)
+    }
+
+    /// Split multicodec prefixed bytes into the multicodec code and the bytes after it.
+    /// Varints that are not minimally encoded or longer than `MULTICODEC_MAX_VARINT_LENGTH` bytes are rejected
+    pub fn from_multicodec(bytes: &[u8]) -> UtilitiesResult<(u64, &[u8])> {
+        let mut code = 0u64;
+
+        for (index, byte) in bytes.iter().take(MULTICODEC_MAX_VARINT_LENGTH).enumerate() {
+            code |= ((*byte & 0x7f) as u64) << (7 * index);
+
+            if byte & 0x80 == 0 {
+                if index > 0 && *byte == 0 {
+                    return Err(UtilitiesError::MulticodecInvalidVarint);
+                }
+
+                return Ok((code, &bytes[index + 1..]));
+            }
+        }
+
+        Err(UtilitiesError::MulticodecInvalidVarint)
+    }
+}
+
+/// Implements multicodec multikey bytes and `did:key` identifiers for the public key types
+macro_rules! impl_did_key {
+    ($($data_type:ident => $code:ident),+ $(,)?) => {
+        $(
+            impl $data_type {
+                /// The multicodec code of the public key
+                pub const MULTICODEC: u64 = $code;
+
+                /// Encode the public key as multicodec prefixed multikey bytes
+                pub fn to_multikey(&self) -> Vec<u8> {
+                    Utilities::to_multicodec(Self::MULTICODEC, &self.0).unwrap() // Never fails since the code is less than `MULTICODEC_MAX_CODE`
+                }
+
+                /// Decode multicodec prefixed multikey bytes,
+                /// rejecting a multicodec code for a different key type
+                pub fn from_multikey(bytes: &[u8]) -> UtilitiesResult<Self> {
+                    let (code, key) = Utilities::from_multicodec(bytes)?;
+
+                    if code != Self::MULTICODEC {
+                        return Err(UtilitiesError::MulticodecMismatch {
+                            expected: Self::MULTICODEC,
+                            actual: code,
+                        });
+                    }
+
+                    Self::try_from(key)
+                }
+
+                /// Encode the public key as a `did:key:z…` identifier
+                pub fn to_did_key(&self) -> String {
+                    DID_KEY_PREFIX.to_owned() + &bs58::encode(self.to_multikey()).into_string()
+                }
+
+                /// Decode a `did:key:z…` identifier,
+                /// rejecting a multicodec code for a different key type
+                pub fn from_did_key(value: &str) -> UtilitiesResult<Self> {
+                    let encoded = match value.strip_prefix(DID_KEY_PREFIX) {
+                        Some(encoded) => encoded,
+                        None => return Err(UtilitiesError::DidKeyInvalidPrefix),
+                    };
+
+                    Self::from_multikey(&bs58::decode(encoded).into_vec()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_did_key!(
+    Ed25519Public => MULTICODEC_ED25519_PUB,
+    X25519Public => MULTICODEC_X25519_PUB,
+    Sr25519Public => MULTICODEC_SR25519_PUB,
+);
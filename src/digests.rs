@@ -0,0 +1,101 @@
+use crate::UtilitiesResult;
+use std::io::{self, Read};
+
+#[cfg(feature = "sha256")]
+use crate::Sha256Hash;
+#[cfg(feature = "sha256")]
+use sha2::Sha256;
+
+#[cfg(feature = "sha512")]
+use crate::Sha512Hash;
+#[cfg(feature = "sha512")]
+use sha2::Sha512;
+
+#[cfg(feature = "sha3")]
+use crate::Sha3_256Hash;
+#[cfg(feature = "sha3")]
+use sha3::Sha3_256;
+
+#[cfg(feature = "keccak")]
+use crate::Keccak256Hash;
+#[cfg(feature = "keccak")]
+use sha3::Keccak256;
+
+#[cfg(any(feature = "sha256", feature = "sha512"))]
+use sha2::Digest as _;
+#[cfg(all(
+    any(feature = "sha3", feature = "keccak"),
+    not(any(feature = "sha256", feature = "sha512"))
+))]
+use sha3::Digest as _;
+
+#[cfg(feature = "sha256")]
+impl Sha256Hash {
+    /// Hash some bytes using SHA-256
+    pub fn hash(bytes: &[u8]) -> Self {
+        Sha256Hash(Sha256::digest(bytes).into())
+    }
+
+    /// Hash some bytes twice using SHA-256 as `SHA256(SHA256(bytes))`
+    /// which is used by Bitcoin for transaction ids and checksums
+    pub fn double_hash(bytes: &[u8]) -> Self {
+        Sha256Hash(Sha256::digest(Sha256::digest(bytes)).into())
+    }
+
+    /// Hash all the bytes read from a reader until end of file using SHA-256
+    pub fn from_reader(mut reader: impl Read) -> UtilitiesResult<Self> {
+        let mut hasher = Sha256::new();
+        io::copy(&mut reader, &mut hasher)?;
+
+        Ok(Sha256Hash(hasher.finalize().into()))
+    }
+}
+
+#[cfg(feature = "sha512")]
+impl Sha512Hash {
+    /// Hash some bytes using SHA-512
+    pub fn hash(bytes: &[u8]) -> Self {
+        Sha512Hash(Sha512::digest(bytes).into())
+    }
+
+    /// Hash all the bytes read from a reader until end of file using SHA-512
+    pub fn from_reader(mut reader: impl Read) -> UtilitiesResult<Self> {
+        let mut hasher = Sha512::new();
+        io::copy(&mut reader, &mut hasher)?;
+
+        Ok(Sha512Hash(hasher.finalize().into()))
+    }
+}
+
+#[cfg(feature = "sha3")]
+impl Sha3_256Hash {
+    /// Hash some bytes using SHA3-256 as standardized in FIPS 202
+    pub fn hash(bytes: &[u8]) -> Self {
+        Sha3_256Hash(Sha3_256::digest(bytes).into())
+    }
+
+    /// Hash all the bytes read from a reader until end of file using SHA3-256
+    pub fn from_reader(mut reader: impl Read) -> UtilitiesResult<Self> {
+        let mut hasher = Sha3_256::new();
+        io::copy(&mut reader, &mut hasher)?;
+
+        Ok(Sha3_256Hash(hasher.finalize().into()))
+    }
+}
+
+#[cfg(feature = "keccak")]
+impl Keccak256Hash {
+    /// Hash some bytes using the original Keccak-256 padding used by Ethereum,
+    /// which produces different hashes from SHA3-256
+    pub fn hash(bytes: &[u8]) -> Self {
+        Keccak256Hash(Keccak256::digest(bytes).into())
+    }
+
+    /// Hash all the bytes read from a reader until end of file using Keccak-256
+    pub fn from_reader(mut reader: impl Read) -> UtilitiesResult<Self> {
+        let mut hasher = Keccak256::new();
+        io::copy(&mut reader, &mut hasher)?;
+
+        Ok(Keccak256Hash(hasher.finalize().into()))
+    }
+}
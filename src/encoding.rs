@@ -0,0 +1,180 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Blake3Hash, Ed25519Public, Ed25519Signature, HexVec,
+    Keccak256Hash, MacTag32, MacTag64, Sha256Hash, Sha3_256Hash, Sha512Hash, Sr25519Public,
+    Sr25519Signature, TaiTimestamp, Utilities, UtilitiesResult, X25519Public,
+};
+
+#[cfg(any(feature = "hex", feature = "base58"))]
+use crate::UtilitiesError;
+#[cfg(any(feature = "hex", feature = "base58"))]
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "base32")]
+use crate::Base32Variant;
+#[cfg(feature = "base64")]
+use crate::Base64Variant;
+#[cfg(feature = "multibase")]
+use crate::Multibase;
+
+/// The text encodings supported by the `TextEncoding` trait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Lowercase hex, decoding also accepts uppercase hex
+    #[cfg(feature = "hex")]
+    Hex,
+    /// Bitcoin base58
+    #[cfg(feature = "base58")]
+    Base58,
+    /// Base64 with the given alphabet and padding
+    #[cfg(feature = "base64")]
+    Base64(Base64Variant),
+    /// Base32 with the given alphabet and padding
+    #[cfg(feature = "base32")]
+    Base32(Base32Variant),
+    /// A multibase string in the given encoding.
+    /// Decoding accepts any supported multibase encoding since the string is self describing
+    #[cfg(feature = "multibase")]
+    Multibase(Multibase),
+}
+
+/// Encode a data type to a string and decode it back using any of the supported encodings.
+/// This is implemented for all the data types except the secret types
+pub trait TextEncoding: Sized {
+    /// Encode the bytes of the data type to a string
+    fn encode(&self, encoding: Encoding) -> String;
+
+    /// Decode a string to the data type.
+    /// Returns `UtilitiesError::InvalidLength` if the decoded length is not the size of the type
+    fn decode(encoding: Encoding, value: &str) -> UtilitiesResult<Self>;
+}
+
+impl Utilities {
+    /// Encode bytes to a string using the given encoding
+    pub fn encode_bytes(bytes: &[u8], encoding: Encoding) -> String {
+        match encoding {
+            #[cfg(feature = "hex")]
+            Encoding::Hex => hex::encode(bytes),
+            #[cfg(feature = "base58")]
+            Encoding::Base58 => bs58::encode(bytes).into_string(),
+            #[cfg(feature = "base64")]
+            Encoding::Base64(variant) => Utilities::bytes_to_base64(bytes, variant),
+            #[cfg(feature = "base32")]
+            Encoding::Base32(variant) => Utilities::bytes_to_base32(bytes, variant),
+            #[cfg(feature = "multibase")]
+            Encoding::Multibase(base) => Utilities::bytes_to_multibase(bytes, base),
+        }
+    }
+
+    /// Decode a string to bytes using the given encoding
+    pub fn decode_bytes(encoding: Encoding, value: &str) -> UtilitiesResult<Vec<u8>> {
+        match encoding {
+            #[cfg(feature = "hex")]
+            Encoding::Hex => Utilities::hex_to_bytes(value),
+            #[cfg(feature = "base58")]
+            Encoding::Base58 => Utilities::base58_to_bytes(value),
+            #[cfg(feature = "base64")]
+            Encoding::Base64(variant) => Utilities::base64_to_bytes(value, variant),
+            #[cfg(feature = "base32")]
+            Encoding::Base32(variant) => Utilities::base32_to_bytes(value, variant),
+            #[cfg(feature = "multibase")]
+            Encoding::Multibase(_) => Ok(Utilities::multibase_to_bytes(value)?.1),
+        }
+    }
+}
+
+/// Implements `TextEncoding` for the fixed size data types that are not secrets
+macro_rules! impl_text_encoding {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl TextEncoding for $data_type {
+                fn encode(&self, encoding: Encoding) -> String {
+                    Utilities::encode_bytes(&self.0, encoding)
+                }
+
+                fn decode(encoding: Encoding, value: &str) -> UtilitiesResult<Self> {
+                    $data_type::try_from(&Utilities::decode_bytes(encoding, value)?[..])
+                }
+            }
+        )+
+    };
+}
+
+impl_text_encoding!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+);
+
+impl TextEncoding for HexVec {
+    fn encode(&self, encoding: Encoding) -> String {
+        Utilities::encode_bytes(&self.0, encoding)
+    }
+
+    fn decode(encoding: Encoding, value: &str) -> UtilitiesResult<Self> {
+        Ok(HexVec(Utilities::decode_bytes(encoding, value)?))
+    }
+}
+
+/// Implements `fmt::Display` and `FromStr` using the natural encoding of each data type,
+/// base58 for Ed25519 and Sr25519 and hex for the hashes, X25519 and the other data types
+macro_rules! impl_display_from_str {
+    ($feature:literal, $encoding:expr, $($data_type:ident),+ $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl fmt::Display for $data_type {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&self.encode($encoding))
+                }
+            }
+
+            #[cfg(feature = $feature)]
+            impl FromStr for $data_type {
+                type Err = UtilitiesError;
+
+                fn from_str(value: &str) -> UtilitiesResult<Self> {
+                    $data_type::decode($encoding, value)
+                }
+            }
+        )+
+    };
+}
+
+impl_display_from_str!(
+    "base58",
+    Encoding::Base58,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+);
+
+impl_display_from_str!(
+    "hex",
+    Encoding::Hex,
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    X25519Public,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+    HexVec,
+);
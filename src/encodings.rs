@@ -0,0 +1,179 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Blake3Hash, Ed25519Public, Ed25519Signature, HexVec,
+    Keccak256Hash, MacTag32, MacTag64, Sha256Hash, Sha3_256Hash, Sha512Hash, Sr25519Public,
+    Sr25519Signature, TaiTimestamp, Utilities, UtilitiesError, UtilitiesResult, X25519Public,
+};
+
+#[cfg(feature = "base64")]
+use base64::{
+    engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+    Engine,
+};
+
+/// The alphabets and padding of Base64 as specified in RFC 4648
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Variant {
+    /// The standard alphabet with `+` and `/` and `=` padding
+    Standard,
+    /// The standard alphabet with `+` and `/` without padding
+    StandardNoPad,
+    /// The URL and filename safe alphabet with `-` and `_` and `=` padding
+    UrlSafe,
+    /// The URL and filename safe alphabet with `-` and `_` without padding,
+    /// used by JWK and JWT
+    UrlSafeNoPad,
+}
+
+#[cfg(feature = "base64")]
+impl Base64Variant {
+    fn engine(&self) -> &'static base64::engine::GeneralPurpose {
+        match self {
+            Base64Variant::Standard => &STANDARD,
+            Base64Variant::StandardNoPad => &STANDARD_NO_PAD,
+            Base64Variant::UrlSafe => &URL_SAFE,
+            Base64Variant::UrlSafeNoPad => &URL_SAFE_NO_PAD,
+        }
+    }
+}
+
+/// The alphabets and padding of Base32
+#[cfg(feature = "base32")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base32Variant {
+    /// The uppercase RFC 4648 alphabet with `=` padding
+    Rfc4648,
+    /// The uppercase RFC 4648 alphabet without padding
+    Rfc4648NoPad,
+    /// The lowercase RFC 4648 alphabet without padding, used by multibase
+    Rfc4648LowerNoPad,
+    /// Douglas Crockford's alphabet which excludes `I`, `L`, `O` and `U`
+    /// and decodes case insensitively, used for human typed identifiers
+    Crockford,
+    /// The human oriented z-base-32 alphabet
+    ZBase32,
+}
+
+#[cfg(feature = "base32")]
+impl Base32Variant {
+    fn alphabet(&self) -> base32::Alphabet {
+        match self {
+            Base32Variant::Rfc4648 => base32::Alphabet::Rfc4648 { padding: true },
+            Base32Variant::Rfc4648NoPad => base32::Alphabet::Rfc4648 { padding: false },
+            Base32Variant::Rfc4648LowerNoPad => base32::Alphabet::Rfc4648Lower { padding: false },
+            Base32Variant::Crockford => base32::Alphabet::Crockford,
+            Base32Variant::ZBase32 => base32::Alphabet::Z,
+        }
+    }
+}
+
+impl Utilities {
+    /// Encode bytes to a base64 string
+    #[cfg(feature = "base64")]
+    pub fn bytes_to_base64(bytes: &[u8], variant: Base64Variant) -> String {
+        variant.engine().encode(bytes)
+    }
+
+    /// Decode a base64 string to bytes
+    #[cfg(feature = "base64")]
+    pub fn base64_to_bytes(value: &str, variant: Base64Variant) -> UtilitiesResult<Vec<u8>> {
+        Ok(variant.engine().decode(value)?)
+    }
+
+    /// Decode a base64 string to a buffer.
+    /// Returns `UtilitiesError::InvalidLength` if the decoded bytes do not fill the buffer
+    #[cfg(feature = "base64")]
+    pub fn base64_to_buffer(
+        value: &str,
+        variant: Base64Variant,
+        buffer: &mut [u8],
+    ) -> UtilitiesResult<()> {
+        let written = variant.engine().decode_slice(value, buffer)?;
+
+        match written == buffer.len() {
+            true => Ok(()),
+            false => Err(UtilitiesError::InvalidLength {
+                expected: buffer.len(),
+                actual: written,
+            }),
+        }
+    }
+
+    /// Encode bytes to a base32 string
+    #[cfg(feature = "base32")]
+    pub fn bytes_to_base32(bytes: &[u8], variant: Base32Variant) -> String {
+        base32::encode(variant.alphabet(), bytes)
+    }
+
+    /// Decode a base32 string to bytes
+    #[cfg(feature = "base32")]
+    pub fn base32_to_bytes(value: &str, variant: Base32Variant) -> UtilitiesResult<Vec<u8>> {
+        match base32::decode(variant.alphabet(), value) {
+            Some(bytes) => Ok(bytes),
+            None => Err(UtilitiesError::Base32InvalidString),
+        }
+    }
+
+    /// Decode a base32 string to a buffer.
+    /// Returns `UtilitiesError::InvalidLength` if the decoded bytes do not fill the buffer
+    #[cfg(feature = "base32")]
+    pub fn base32_to_buffer(
+        value: &str,
+        variant: Base32Variant,
+        buffer: &mut [u8],
+    ) -> UtilitiesResult<()> {
+        let bytes = Utilities::base32_to_bytes(value, variant)?;
+
+        match bytes.len() == buffer.len() {
+            true => {
+                buffer.copy_from_slice(&bytes);
+                Ok(())
+            }
+            false => Err(UtilitiesError::InvalidLength {
+                expected: buffer.len(),
+                actual: bytes.len(),
+            }),
+        }
+    }
+}
+
+/// Implements `to_base64` and `to_base32` for the data types that are not secrets
+macro_rules! impl_text_encodings {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl $data_type {
+                /// Return the `base64` representation of the bytes
+                #[cfg(feature = "base64")]
+                pub fn to_base64(&self, variant: Base64Variant) -> String {
+                    Utilities::bytes_to_base64(&self.0, variant)
+                }
+
+                /// Return the `base32` representation of the bytes
+                #[cfg(feature = "base32")]
+                pub fn to_base32(&self, variant: Base32Variant) -> String {
+                    Utilities::bytes_to_base32(&self.0, variant)
+                }
+            }
+        )+
+    };
+}
+
+impl_text_encodings!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+    HexVec,
+);
@@ -0,0 +1,242 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Blake3Hash, BorshLimits, BorshStrict, Ed25519Public,
+    Ed25519Signature, HexVec, Keccak256Hash, MacTag32, MacTag64, Secret32Bytes, SecretVec,
+    Sha256Hash, Sha3_256Hash, Sha512Hash, Sr25519Public, Sr25519Signature, TaiTimestamp, Threshold,
+    Utilities, UtilitiesError, UtilitiesResult, Vote, VoteOutcome, VoteTally, X25519Public,
+};
+use borsh::BorshSerialize;
+
+/// The magic number at the start of every envelope
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"W3UE";
+/// The length of the envelope header, the magic number, the `u16` type tag
+/// and the `u16` format version
+pub const ENVELOPE_HEADER_LENGTH: usize = 8;
+
+/// The registry of type tags for the types that can be stored in an envelope.
+/// The tags are part of the stored format, new types must be given new tags
+/// and existing tags must never be reused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum TypeTag {
+    /// `Blake3Hash`
+    Blake3Hash = 1,
+    /// `Sha256Hash`
+    Sha256Hash = 2,
+    /// `Sha512Hash`
+    Sha512Hash = 3,
+    /// `Sha3_256Hash`
+    Sha3_256Hash = 4,
+    /// `Keccak256Hash`
+    Keccak256Hash = 5,
+    /// `TaiTimestamp`
+    TaiTimestamp = 6,
+    /// `Ed25519Public`
+    Ed25519Public = 7,
+    /// `Ed25519Signature`
+    Ed25519Signature = 8,
+    /// `Sr25519Public`
+    Sr25519Public = 9,
+    /// `Sr25519Signature`
+    Sr25519Signature = 10,
+    /// `X25519Public`
+    X25519Public = 11,
+    /// `Secret32Bytes`
+    Secret32Bytes = 12,
+    /// `SecretVec`
+    SecretVec = 13,
+    /// `HexVec`
+    HexVec = 14,
+    /// `AeadNonce`
+    AeadNonce = 15,
+    /// `AeadXNonce`
+    AeadXNonce = 16,
+    /// `AeadTag`
+    AeadTag = 17,
+    /// `MacTag32`
+    MacTag32 = 18,
+    /// `MacTag64`
+    MacTag64 = 19,
+    /// `VoteTally`
+    VoteTally = 20,
+    /// `Vote`
+    Vote = 21,
+    /// `VoteOutcome`
+    VoteOutcome = 22,
+    /// `Threshold`
+    Threshold = 23,
+}
+
+impl TypeTag {
+    /// All the registered type tags
+    pub const ALL: [TypeTag; 23] = [
+        TypeTag::Blake3Hash,
+        TypeTag::Sha256Hash,
+        TypeTag::Sha512Hash,
+        TypeTag::Sha3_256Hash,
+        TypeTag::Keccak256Hash,
+        TypeTag::TaiTimestamp,
+        TypeTag::Ed25519Public,
+        TypeTag::Ed25519Signature,
+        TypeTag::Sr25519Public,
+        TypeTag::Sr25519Signature,
+        TypeTag::X25519Public,
+        TypeTag::Secret32Bytes,
+        TypeTag::SecretVec,
+        TypeTag::HexVec,
+        TypeTag::AeadNonce,
+        TypeTag::AeadXNonce,
+        TypeTag::AeadTag,
+        TypeTag::MacTag32,
+        TypeTag::MacTag64,
+        TypeTag::VoteTally,
+        TypeTag::Vote,
+        TypeTag::VoteOutcome,
+        TypeTag::Threshold,
+    ];
+
+    /// The `u16` stored in the envelope header
+    pub fn to_u16(self) -> u16 {
+        self as u16
+    }
+
+    /// Get the type tag from the `u16` stored in the envelope header
+    pub fn from_u16(value: u16) -> UtilitiesResult<Self> {
+        match TypeTag::ALL.iter().find(|tag| tag.to_u16() == value) {
+            Some(tag) => Ok(*tag),
+            None => Err(UtilitiesError::EnvelopeUnknownTypeTag { tag: value }),
+        }
+    }
+}
+
+/// The header of an envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvelopeHeader {
+    /// The type of the value in the payload
+    pub type_tag: TypeTag,
+    /// The format version of the payload
+    pub version: u16,
+}
+
+/// The types that can be stored in a versioned and type tagged envelope
+/// made up of `ENVELOPE_MAGIC`, the `u16` little endian type tag,
+/// the `u16` little endian format version and the Borsh payload
+pub trait Enveloped: BorshSerialize + BorshStrict {
+    /// The tag of the type in the registry
+    const TYPE_TAG: TypeTag;
+    /// The current format version of the Borsh payload
+    const ENVELOPE_VERSION: u16 = 1;
+
+    /// Encode the value into an envelope with the current format version
+    fn to_envelope(&self) -> UtilitiesResult<Vec<u8>> {
+        let mut envelope = Vec::with_capacity(ENVELOPE_HEADER_LENGTH);
+        envelope.extend_from_slice(&ENVELOPE_MAGIC);
+        envelope.extend_from_slice(&Self::TYPE_TAG.to_u16().to_le_bytes());
+        envelope.extend_from_slice(&Self::ENVELOPE_VERSION.to_le_bytes());
+        self.serialize(&mut envelope)?;
+
+        Ok(envelope)
+    }
+
+    /// Decode an envelope with the current format version.
+    /// Envelopes with an older format version are rejected,
+    /// use `Utilities::from_envelope_with_migration` to read them
+    fn from_envelope(bytes: &[u8], limits: BorshLimits) -> UtilitiesResult<Self> {
+        Utilities::from_envelope_with_migration(bytes, limits, |version, _| {
+            Err(UtilitiesError::EnvelopeUnsupportedVersion { version })
+        })
+    }
+}
+
+impl Utilities {
+    /// Read the header of an envelope without decoding the payload
+    pub fn envelope_header(bytes: &[u8]) -> UtilitiesResult<EnvelopeHeader> {
+        if bytes.len() < ENVELOPE_HEADER_LENGTH {
+            return Err(UtilitiesError::EnvelopeTruncated);
+        }
+
+        if bytes[..4] != ENVELOPE_MAGIC {
+            return Err(UtilitiesError::EnvelopeInvalidMagic);
+        }
+
+        let type_tag = TypeTag::from_u16(u16::from_le_bytes([bytes[4], bytes[5]]))?;
+        let version = u16::from_le_bytes([bytes[6], bytes[7]]);
+
+        Ok(EnvelopeHeader { type_tag, version })
+    }
+
+    /// Decode an envelope, passing the format version and the payload of envelopes
+    /// with an older format version to `migrate` which converts them to the current type.
+    /// The payload of the current format version is decoded using `Utilities::borsh_decode_strict`.
+    /// Payloads longer than `limits.max_input_length` are rejected before either is called
+    pub fn from_envelope_with_migration<T, F>(
+        bytes: &[u8],
+        limits: BorshLimits,
+        migrate: F,
+    ) -> UtilitiesResult<T>
+    where
+        T: Enveloped,
+        F: FnOnce(u16, &[u8]) -> UtilitiesResult<T>,
+    {
+        let header = Utilities::envelope_header(bytes)?;
+
+        if header.type_tag != T::TYPE_TAG {
+            return Err(UtilitiesError::EnvelopeTypeMismatch {
+                expected: T::TYPE_TAG.to_u16(),
+                actual: header.type_tag.to_u16(),
+            });
+        }
+
+        let payload = &bytes[ENVELOPE_HEADER_LENGTH..];
+        if payload.len() > limits.max_input_length {
+            return Err(UtilitiesError::BorshInputTooLong {
+                max: limits.max_input_length,
+                actual: payload.len(),
+            });
+        }
+
+        match header.version {
+            version if version == T::ENVELOPE_VERSION => {
+                Utilities::borsh_decode_strict(payload, limits)
+            }
+            version if version < T::ENVELOPE_VERSION => migrate(version, payload),
+            version => Err(UtilitiesError::EnvelopeUnsupportedVersion { version }),
+        }
+    }
+}
+
+/// Implements `Enveloped` for the types in the type tag registry
+macro_rules! impl_enveloped {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl Enveloped for $data_type {
+                const TYPE_TAG: TypeTag = TypeTag::$data_type;
+            }
+        )+
+    };
+}
+
+impl_enveloped!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    Secret32Bytes,
+    SecretVec,
+    HexVec,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+    VoteTally,
+    Vote,
+    VoteOutcome,
+    Threshold,
+);
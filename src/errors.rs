@@ -6,6 +6,7 @@ pub type UtilitiesResult<T> = Result<T, UtilitiesError>;
 
 /// Common Errors
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UtilitiesError {
     /// The byte length is less than 12 bytes
     LengthLessThan12Bytes,
@@ -114,6 +115,203 @@ pub enum UtilitiesError {
     MemoryCouldNotbeZeroized,
     /// `std::io::ErrorKind` conversion
     Io(IoErrorKind),
+    /// The byte length is not the length required by the array or data structure
+    InvalidLength {
+        /// The number of bytes required
+        expected: usize,
+        /// The number of bytes provided
+        actual: usize,
+    },
+    /// The public key did not sign the provided signature under the signing context
+    InvalidSr25519Signature,
+    /// Some signatures in a batch were invalid
+    InvalidSignaturesInBatch {
+        /// The indices of the invalid signatures in the batch
+        indices: Vec<usize>,
+    },
+    /// The X25519 shared secret is all zeros since the public key
+    /// provided is a low order point
+    X25519NonContributorySharedSecret,
+    /// The bytes provided could not be encrypted
+    XChaCha20Poly1305EncryptionError,
+    /// The encrypted bytes provided could not be decrypted
+    XChaCha20Poly1305DecryptionError,
+    /// The bytes provided could not be encrypted
+    ChaCha20Poly1305EncryptionError,
+    /// The encrypted bytes provided could not be decrypted
+    ChaCha20Poly1305DecryptionError,
+    /// The bytes provided could not be encrypted
+    Aes256GcmEncryptionError,
+    /// The encrypted bytes provided could not be decrypted
+    Aes256GcmDecryptionError,
+    /// The Merkle proof does not prove that the leaf is included in the tree
+    InvalidMerkleProof,
+    /// The consistency proof does not prove that the old Merkle Mountain Range
+    /// is a prefix of the new Merkle Mountain Range
+    InvalidMmrConsistencyProof,
+    /// The message authentication code does not match the message and key
+    InvalidMac,
+    /// The checksum of the Base58Check string does not match the double SHA-256 checksum
+    /// of its payload
+    Base58CheckInvalidChecksum,
+    /// The Base58Check string does not start with the expected version prefix
+    Base58CheckInvalidPrefix,
+    /// The SS58 network prefix is greater than 16383 or the first byte
+    /// of the SS58 address is not a valid prefix encoding
+    Ss58InvalidPrefix,
+    /// The checksum of the SS58 address does not match the Blake2b-512 checksum of its payload
+    Ss58InvalidChecksum,
+    /// The Bech32 string does not contain the `1` separator
+    Bech32MissingSeparator,
+    /// The checksum of the Bech32 string does not match its human readable part and data
+    Bech32InvalidChecksum,
+    /// The Bech32 string is longer than 90 characters or its parts are too long or too short
+    Bech32InvalidLength,
+    /// The Bech32 string contains an invalid character
+    Bech32InvalidCharacter {
+        /// The invalid character
+        character: String,
+    },
+    /// The data of the Bech32 string is not padded correctly or has an invalid value
+    Bech32InvalidData,
+    /// The Bech32 string contains both uppercase and lowercase characters
+    Bech32MixedCase,
+    /// The human readable part of the Bech32 string is not the expected one
+    Bech32InvalidHrp,
+    /// The checksum of the Bech32 string is not the expected Bech32 or Bech32m variant
+    Bech32InvalidVariant,
+    /// Mirrors the error for `base64` crate
+    Base64InvalidByte {
+        /// The index of the invalid byte
+        index: usize,
+        /// The invalid byte
+        byte: u8,
+    },
+    /// The number of valid base64 symbols is invalid
+    Base64InvalidLength,
+    /// Mirrors the error for `base64` crate where the last symbol has bits
+    /// that would be discarded
+    Base64InvalidLastSymbol {
+        /// The index of the last symbol
+        index: usize,
+        /// The last symbol
+        byte: u8,
+    },
+    /// The base64 padding is missing or not expected
+    Base64InvalidPadding,
+    /// The buffer is too small for the decoded base64 bytes
+    Base64BufferTooSmall,
+    /// The string contains characters that are not in the base32 alphabet
+    Base32InvalidString,
+    /// The multibase string is empty so it has no prefix character
+    MultibaseEmptyString,
+    /// The prefix character of the multibase string is not a supported encoding
+    MultibaseUnsupportedPrefix {
+        /// The prefix character
+        prefix: String,
+    },
+    /// The Borsh bytes are longer than the maximum input length
+    BorshInputTooLong {
+        /// The maximum number of bytes
+        max: usize,
+        /// The number of bytes
+        actual: usize,
+    },
+    /// The length prefix of a Borsh encoded `Vec` is greater than the maximum length
+    BorshVecTooLong {
+        /// The maximum length
+        max: usize,
+        /// The length in the prefix
+        actual: usize,
+    },
+    /// Bytes were left over after decoding the Borsh value
+    BorshTrailingBytes {
+        /// The number of bytes left over
+        count: usize,
+    },
+    /// The Borsh bytes could not be decoded into a value of the type
+    BorshInvalidData,
+    /// The bytes are shorter than the envelope header
+    EnvelopeTruncated,
+    /// The bytes do not start with the envelope magic number
+    EnvelopeInvalidMagic,
+    /// The type tag in the envelope header is not in the registry
+    EnvelopeUnknownTypeTag {
+        /// The type tag
+        tag: u16,
+    },
+    /// The envelope holds a value of a different type
+    EnvelopeTypeMismatch {
+        /// The type tag of the type being decoded
+        expected: u16,
+        /// The type tag in the envelope header
+        actual: u16,
+    },
+    /// The format version of the envelope cannot be decoded
+    EnvelopeUnsupportedVersion {
+        /// The format version in the envelope header
+        version: u16,
+    },
+    /// The multicodec code is not a valid unsigned varint
+    MulticodecInvalidVarint,
+    /// The multicodec code is for a different key type
+    MulticodecMismatch {
+        /// The multicodec code of the key type being decoded
+        expected: u64,
+        /// The multicodec code in the bytes
+        actual: u64,
+    },
+    /// The identifier does not start with `did:key:z`
+    DidKeyInvalidPrefix,
+    /// The JSON Web Key is not valid JSON or is missing a required member
+    JwkInvalidJson,
+    /// The `kty` member of the JSON Web Key is not `OKP`
+    JwkUnsupportedKeyType {
+        /// The `kty` member
+        key_type: String,
+    },
+    /// The `crv` member of the JSON Web Key is not the curve of the key type
+    JwkUnsupportedCurve {
+        /// The `crv` member
+        curve: String,
+    },
+    /// The JSON Web Key has no private `d` member
+    JwkMissingPrivateKey,
+    /// The `x` member of the JSON Web Key is not the public key of the private `d` member
+    JwkPublicKeyMismatch,
+    /// The bytes are not a valid DER document of the expected ASN.1 structure
+    DerInvalidEncoding,
+    /// The string is not a valid PEM document
+    PemInvalidEncoding,
+    /// The label of the PEM document is not the label of the expected document
+    PemUnexpectedLabel {
+        /// The label of the PEM document
+        label: String,
+    },
+    /// The algorithm identifier of the key is not the algorithm of the key type
+    KeyAlgorithmMismatch {
+        /// The object identifier of the algorithm
+        oid: String,
+    },
+    /// The algorithm identifier of the key has parameters which RFC 8410 requires to be absent
+    KeyAlgorithmParametersPresent,
+    /// The private key of the PKCS#8 document is not a 32 byte OCTET STRING
+    Pkcs8InvalidPrivateKey,
+    /// The public key of the PKCS#8 v2 document is not the public key of the private key
+    Pkcs8PublicKeyMismatch,
+    /// The encrypted PKCS#8 document could not be decrypted, usually because the password is wrong
+    Pkcs8DecryptionFailed,
+    /// The PKCS#8 document could not be encrypted
+    Pkcs8EncryptionFailed,
+    /// The encryption scheme of the encrypted PKCS#8 document is not supported
+    Pkcs8UnsupportedEncryption {
+        /// The object identifier of the encryption scheme
+        oid: String,
+    },
+    /// The parameters of the encryption scheme are invalid
+    Pkcs8InvalidEncryptionParameters,
+    /// The parameters of the algorithm identifier of the key are malformed
+    KeyAlgorithmParametersMalformed,
 }
 
 #[cfg(feature = "tai64")]
@@ -159,6 +357,50 @@ impl From<bs58::encode::Error> for UtilitiesError {
     }
 }
 
+#[cfg(feature = "bech32")]
+impl From<bech32::Error> for UtilitiesError {
+    fn from(error: bech32::Error) -> Self {
+        match error {
+            bech32::Error::MissingSeparator => UtilitiesError::Bech32MissingSeparator,
+            bech32::Error::InvalidChecksum => UtilitiesError::Bech32InvalidChecksum,
+            bech32::Error::InvalidLength => UtilitiesError::Bech32InvalidLength,
+            bech32::Error::InvalidChar(character) => UtilitiesError::Bech32InvalidCharacter {
+                character: character.to_string(),
+            },
+            bech32::Error::InvalidData(_) | bech32::Error::InvalidPadding => {
+                UtilitiesError::Bech32InvalidData
+            }
+            bech32::Error::MixedCase => UtilitiesError::Bech32MixedCase,
+        }
+    }
+}
+
+#[cfg(feature = "base64")]
+impl From<base64::DecodeError> for UtilitiesError {
+    fn from(error: base64::DecodeError) -> Self {
+        match error {
+            base64::DecodeError::InvalidByte(index, byte) => {
+                UtilitiesError::Base64InvalidByte { index, byte }
+            }
+            base64::DecodeError::InvalidLength(_) => UtilitiesError::Base64InvalidLength,
+            base64::DecodeError::InvalidLastSymbol(index, byte) => {
+                UtilitiesError::Base64InvalidLastSymbol { index, byte }
+            }
+            base64::DecodeError::InvalidPadding => UtilitiesError::Base64InvalidPadding,
+        }
+    }
+}
+
+#[cfg(feature = "base64")]
+impl From<base64::DecodeSliceError> for UtilitiesError {
+    fn from(error: base64::DecodeSliceError) -> Self {
+        match error {
+            base64::DecodeSliceError::DecodeError(error) => error.into(),
+            base64::DecodeSliceError::OutputSliceTooSmall => UtilitiesError::Base64BufferTooSmall,
+        }
+    }
+}
+
 #[cfg(feature = "hex")]
 impl From<hex::FromHexError> for UtilitiesError {
     fn from(error: hex::FromHexError) -> Self {
@@ -206,6 +448,7 @@ impl From<std::io::Error> for UtilitiesError {
 
 /// Implemetation for `From<std::io::ErrorKind>` for this crate
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IoErrorKind {
     /// An entity was not found, often a file.
     NotFound,
@@ -258,3 +501,71 @@ pub enum IoErrorKind {
     /// `ErrorKind` encountered is not yet supported
     UnsupportedErrorKind,
 }
+
+#[cfg(feature = "pkcs8")]
+impl From<pkcs8::der::Error> for UtilitiesError {
+    fn from(error: pkcs8::der::Error) -> Self {
+        match error.kind() {
+            pkcs8::der::ErrorKind::Pem(_) => UtilitiesError::PemInvalidEncoding,
+            _ => UtilitiesError::DerInvalidEncoding,
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<pkcs8::der::pem::Error> for UtilitiesError {
+    fn from(_: pkcs8::der::pem::Error) -> Self {
+        UtilitiesError::PemInvalidEncoding
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<pkcs8::spki::Error> for UtilitiesError {
+    fn from(error: pkcs8::spki::Error) -> Self {
+        match error {
+            pkcs8::spki::Error::Asn1(error) => error.into(),
+            pkcs8::spki::Error::OidUnknown { oid } => UtilitiesError::KeyAlgorithmMismatch {
+                oid: oid.to_string(),
+            },
+            _ => UtilitiesError::DerInvalidEncoding,
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<pkcs8::pkcs5::Error> for UtilitiesError {
+    fn from(error: pkcs8::pkcs5::Error) -> Self {
+        match error {
+            pkcs8::pkcs5::Error::DecryptFailed => UtilitiesError::Pkcs8DecryptionFailed,
+            pkcs8::pkcs5::Error::EncryptFailed => UtilitiesError::Pkcs8EncryptionFailed,
+            pkcs8::pkcs5::Error::AlgorithmParametersInvalid { .. } => {
+                UtilitiesError::Pkcs8InvalidEncryptionParameters
+            }
+            pkcs8::pkcs5::Error::UnsupportedAlgorithm { oid } => {
+                UtilitiesError::Pkcs8UnsupportedEncryption {
+                    oid: oid.to_string(),
+                }
+            }
+            pkcs8::pkcs5::Error::NoPbes1CryptSupport => {
+                UtilitiesError::Pkcs8UnsupportedEncryption {
+                    oid: "PBES1".to_owned(),
+                }
+            }
+            _ => UtilitiesError::Pkcs8InvalidEncryptionParameters,
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<pkcs8::Error> for UtilitiesError {
+    fn from(error: pkcs8::Error) -> Self {
+        match error {
+            pkcs8::Error::Asn1(error) => error.into(),
+            pkcs8::Error::EncryptedPrivateKey(error) => error.into(),
+            pkcs8::Error::KeyMalformed => UtilitiesError::Pkcs8InvalidPrivateKey,
+            pkcs8::Error::ParametersMalformed => UtilitiesError::KeyAlgorithmParametersMalformed,
+            pkcs8::Error::PublicKey(error) => error.into(),
+            _ => UtilitiesError::DerInvalidEncoding,
+        }
+    }
+}
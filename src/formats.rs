@@ -4,82 +4,83 @@ use crate::{Utilities, UtilitiesError, UtilitiesResult};
 use std::time::SystemTime;
 
 impl Utilities {
-    /// Convert some bytes to a 12 byte array
-    pub fn to_12byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 12]> {
-        if bytes.len() < 12 {
-            return Err(UtilitiesError::LengthLessThan12Bytes);
-        }
-
-        if bytes.len() > 12 {
-            return Err(UtilitiesError::LengthGreaterThan12Bytes);
+    /// Convert some bytes to an array of `N` bytes.
+    /// Returns `UtilitiesError::InvalidLength` if the length of the bytes is not exactly `N`
+    pub fn to_byte_array<const N: usize>(bytes: &[u8]) -> UtilitiesResult<[u8; N]> {
+        match bytes.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => Err(UtilitiesError::InvalidLength {
+                expected: N,
+                actual: bytes.len(),
+            }),
         }
+    }
 
-        Ok(bytes.try_into().unwrap()) // Never fails due to checks above
+    /// Convert some bytes to a 12 byte array
+    pub fn to_12byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 12]> {
+        Utilities::to_sized_byte_array(
+            bytes,
+            UtilitiesError::LengthLessThan12Bytes,
+            UtilitiesError::LengthGreaterThan12Bytes,
+        )
     }
 
     /// Convert some bytes to a 16 byte array
     pub fn to_16byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 16]> {
-        if bytes.len() < 16 {
-            return Err(UtilitiesError::LengthLessThan16Bytes);
-        }
-
-        if bytes.len() > 16 {
-            return Err(UtilitiesError::LengthGreaterThan16Bytes);
-        }
-
-        Ok(bytes.try_into().unwrap()) // Never fails due to checks above
+        Utilities::to_sized_byte_array(
+            bytes,
+            UtilitiesError::LengthLessThan16Bytes,
+            UtilitiesError::LengthGreaterThan16Bytes,
+        )
     }
 
     /// Convert some bytes to a 24 byte array
     pub fn to_24byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 24]> {
-        if bytes.len() < 24 {
-            return Err(UtilitiesError::LengthLessThan24Bytes);
-        }
-
-        if bytes.len() > 24 {
-            return Err(UtilitiesError::LengthGreaterThan24Bytes);
-        }
-
-        Ok(bytes.try_into().unwrap()) // Never fails due to checks above
+        Utilities::to_sized_byte_array(
+            bytes,
+            UtilitiesError::LengthLessThan24Bytes,
+            UtilitiesError::LengthGreaterThan24Bytes,
+        )
     }
 
     /// Convert some bytes to a 32 byte array
     pub fn to_32byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 32]> {
-        if bytes.len() < 32 {
-            return Err(UtilitiesError::LengthLessThan32Bytes);
-        }
-
-        if bytes.len() > 32 {
-            return Err(UtilitiesError::LengthGreaterThan32Bytes);
-        }
-
-        Ok(bytes.try_into().unwrap()) // Never fails due to checks above
+        Utilities::to_sized_byte_array(
+            bytes,
+            UtilitiesError::LengthLessThan32Bytes,
+            UtilitiesError::LengthGreaterThan32Bytes,
+        )
     }
 
     /// Convert some bytes to a 64 byte array
     pub fn to_64byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 64]> {
-        if bytes.len() < 64 {
-            return Err(UtilitiesError::LengthLessThan64Bytes);
-        }
-
-        if bytes.len() > 64 {
-            return Err(UtilitiesError::LengthGreaterThan64Bytes);
-        }
-
-        Ok(bytes.try_into().unwrap()) // Never fails due to checks above
+        Utilities::to_sized_byte_array(
+            bytes,
+            UtilitiesError::LengthLessThan64Bytes,
+            UtilitiesError::LengthGreaterThan64Bytes,
+        )
     }
 
     /// Convert some bytes to a 128 byte array
     pub fn to_128byte_array(bytes: &[u8]) -> UtilitiesResult<[u8; 128]> {
-        if bytes.len() < 128 {
-            return Err(UtilitiesError::LengthLessThan128Bytes);
+        Utilities::to_sized_byte_array(
+            bytes,
+            UtilitiesError::LengthLessThan128Bytes,
+            UtilitiesError::LengthGreaterThan128Bytes,
+        )
+    }
+
+    // Maps `UtilitiesError::InvalidLength` to the errors returned by the fixed size conversions
+    fn to_sized_byte_array<const N: usize>(
+        bytes: &[u8],
+        less_than: UtilitiesError,
+        greater_than: UtilitiesError,
+    ) -> UtilitiesResult<[u8; N]> {
+        match Utilities::to_byte_array::<N>(bytes) {
+            Ok(array) => Ok(array),
+            Err(_) if bytes.len() < N => Err(less_than),
+            Err(_) => Err(greater_than),
         }
-
-        if bytes.len() > 128 {
-            return Err(UtilitiesError::LengthGreaterThan128Bytes);
-        }
-
-        Ok(bytes.try_into().unwrap()) // Never fails due to checks above
     }
 
     /// decode hex to bytes
@@ -0,0 +1,130 @@
+use crate::{Blake3Hash, Secret32Bytes, UtilitiesResult};
+use core::fmt;
+use std::{io::Read, path::Path};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+impl Blake3Hash {
+    /// Hash some bytes using Blake3
+    pub fn hash(bytes: &[u8]) -> Self {
+        Blake3Hash(*blake3::hash(bytes).as_bytes())
+    }
+
+    /// Hash some bytes using Blake3 in keyed mode, useful as a message authentication code.
+    /// The output is a `Blake3Hash` which is compared in constant time
+    pub fn keyed_hash(key: &Secret32Bytes, bytes: &[u8]) -> Self {
+        Blake3Hash(*blake3::keyed_hash(&key.0, bytes).as_bytes())
+    }
+
+    /// Derive a secret key from some key material using Blake3 in key derivation mode.
+    /// The `context` should be hardcoded, globally unique and application specific,
+    /// for example `"Web3Utilities 2022-10-01 session keys"`
+    pub fn derive_key(context: &str, key_material: &[u8]) -> Secret32Bytes {
+        Secret32Bytes(blake3::derive_key(context, key_material))
+    }
+
+    /// Hash all the bytes read from a reader until end of file using Blake3
+    pub fn from_reader(reader: impl Read) -> UtilitiesResult<Self> {
+        Ok(Blake3Hasher::new().update_reader(reader)?.finalize())
+    }
+
+    /// Hash the contents of a file using Blake3.
+    /// The file is memory mapped and hashed using multiple threads when
+    /// the `rayon` feature is enabled, which is faster for very large files
+    pub fn from_file(path: impl AsRef<Path>) -> UtilitiesResult<Self> {
+        Ok(Blake3Hasher::new().update_file(path)?.finalize())
+    }
+}
+
+/// An incremental Blake3 hasher whose output is a `Blake3Hash`.
+/// The state of the hasher, which contains the key in keyed mode,
+/// is zeroed when the hasher is dropped
+pub struct Blake3Hasher(blake3::Hasher);
+
+impl Blake3Hasher {
+    /// Create a new Blake3 hasher in the default hashing mode
+    pub fn new() -> Self {
+        Blake3Hasher(blake3::Hasher::new())
+    }
+
+    /// Create a new Blake3 hasher in keyed mode
+    pub fn new_keyed(key: &Secret32Bytes) -> Self {
+        Blake3Hasher(blake3::Hasher::new_keyed(&key.0))
+    }
+
+    /// Create a new Blake3 hasher in key derivation mode
+    pub fn new_derive_key(context: &str) -> Self {
+        Blake3Hasher(blake3::Hasher::new_derive_key(context))
+    }
+
+    /// Add more bytes to the hasher
+    pub fn update(&mut self, bytes: &[u8]) -> &mut Self {
+        self.0.update(bytes);
+
+        self
+    }
+
+    /// Add all the bytes read from a reader until end of file to the hasher
+    pub fn update_reader(&mut self, reader: impl Read) -> UtilitiesResult<&mut Self> {
+        self.0.update_reader(reader)?;
+
+        Ok(self)
+    }
+
+    /// Add the contents of a file to the hasher by memory mapping the file.
+    /// Small files are read normally since memory mapping is slower for them
+    #[cfg(not(feature = "rayon"))]
+    pub fn update_file(&mut self, path: impl AsRef<Path>) -> UtilitiesResult<&mut Self> {
+        self.0.update_mmap(path)?;
+
+        Ok(self)
+    }
+
+    /// Add the contents of a file to the hasher by memory mapping the file
+    /// and hashing it using multiple threads.
+    /// Small files are read normally since memory mapping is slower for them
+    #[cfg(feature = "rayon")]
+    pub fn update_file(&mut self, path: impl AsRef<Path>) -> UtilitiesResult<&mut Self> {
+        self.0.update_mmap_rayon(path)?;
+
+        Ok(self)
+    }
+
+    /// Get the `Blake3Hash` of all the bytes added so far.
+    /// More bytes can still be added after this
+    pub fn finalize(&self) -> Blake3Hash {
+        Blake3Hash(*self.0.finalize().as_bytes())
+    }
+
+    /// Reset the hasher to its initial state while retaining the key or context
+    pub fn reset(&mut self) -> &mut Self {
+        self.0.reset();
+
+        self
+    }
+}
+
+impl Default for Blake3Hasher {
+    fn default() -> Self {
+        Blake3Hasher::new()
+    }
+}
+
+impl Zeroize for Blake3Hasher {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for Blake3Hasher {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Blake3Hasher {}
+
+impl fmt::Debug for Blake3Hasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Blake3Hasher").field(&"[REDACTED]").finish()
+    }
+}
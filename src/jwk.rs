@@ -0,0 +1,273 @@
+use crate::{
+    Base64Variant, Ed25519Public, Sha256Hash, Utilities, UtilitiesError, UtilitiesResult,
+    X25519Public,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+use crate::Secret32Bytes;
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+use zeroize::Zeroize;
+
+#[cfg(feature = "ed25519")]
+use crate::Ed25519Vault;
+#[cfg(feature = "x25519")]
+use crate::X25519Secret;
+
+/// The JWK key type of Ed25519 and X25519 keys, octet key pair
+pub const JWK_KEY_TYPE_OKP: &str = "OKP";
+
+/// The curves of an `OKP` JSON Web Key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JwkCurve {
+    /// `Ed25519` signing keys
+    Ed25519,
+    /// `X25519` key agreement keys
+    X25519,
+}
+
+impl JwkCurve {
+    /// The `crv` name of the curve
+    pub fn name(&self) -> &'static str {
+        match self {
+            JwkCurve::Ed25519 => "Ed25519",
+            JwkCurve::X25519 => "X25519",
+        }
+    }
+
+    /// Get the curve from the `crv` name
+    pub fn from_name(name: &str) -> UtilitiesResult<Self> {
+        match name {
+            "Ed25519" => Ok(JwkCurve::Ed25519),
+            "X25519" => Ok(JwkCurve::X25519),
+            _ => Err(UtilitiesError::JwkUnsupportedCurve {
+                curve: name.to_owned(),
+            }),
+        }
+    }
+}
+
+/// The public members of an `OKP` JSON Web Key (RFC 8037).
+/// The private `d` member is never part of this type, it is only read and written
+/// by the secret key types using `from_jwk` and `dangerous_export_jwk`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Jwk {
+    /// The key type, always `OKP`
+    pub kty: String,
+    /// The curve name
+    pub crv: String,
+    /// The base64url encoded public key
+    pub x: String,
+    /// The optional key ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+impl Jwk {
+    /// Create a JWK from the curve and the 32 public key bytes
+    pub fn new(curve: JwkCurve, public_key: &[u8; 32]) -> Self {
+        Jwk {
+            kty: JWK_KEY_TYPE_OKP.to_owned(),
+            crv: curve.name().to_owned(),
+            x: Utilities::bytes_to_base64(public_key, Base64Variant::UrlSafeNoPad),
+            kid: Option::default(),
+        }
+    }
+
+    /// Set the key ID to the RFC 7638 thumbprint of the key
+    pub fn with_thumbprint_kid(mut self) -> Self {
+        self.kid = Some(self.thumbprint());
+
+        self
+    }
+
+    /// Encode the JWK as a JSON string
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap() // Never fails since all the members are strings
+    }
+
+    /// Decode a JWK from a JSON string, members other than `kty`, `crv`, `x` and `kid` are ignored
+    pub fn from_json(value: &str) -> UtilitiesResult<Self> {
+        match serde_json::from_str(value) {
+            Ok(jwk) => Ok(jwk),
+            Err(_) => Err(UtilitiesError::JwkInvalidJson),
+        }
+    }
+
+    /// The RFC 7638 thumbprint of the key, the base64url encoded SHA-256 hash of the
+    /// required members `crv`, `kty` and `x` in lexicographic order without whitespace
+    pub fn thumbprint(&self) -> String {
+        let canonical = serde_json::json!({
+            "crv": self.crv,
+            "kty": self.kty,
+            "x": self.x,
+        });
+
+        Utilities::bytes_to_base64(
+            &Sha256Hash::hash(canonical.to_string().as_bytes()).0,
+            Base64Variant::UrlSafeNoPad,
+        )
+    }
+
+    /// Check the key type and the curve and decode the 32 byte public key
+    pub fn public_key(&self, curve: JwkCurve) -> UtilitiesResult<[u8; 32]> {
+        Utilities::check_jwk(&self.kty, &self.crv, curve)?;
+
+        let mut public_key = [0u8; 32];
+        Utilities::base64_to_buffer(&self.x, Base64Variant::UrlSafeNoPad, &mut public_key)?;
+
+        Ok(public_key)
+    }
+}
+
+// The members of an `OKP` JSON Web Key including the private `d` member
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+#[derive(Serialize, Deserialize)]
+struct SecretJwk {
+    kty: String,
+    crv: String,
+    x: String,
+    d: Option<String>,
+}
+
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+impl Drop for SecretJwk {
+    fn drop(&mut self) {
+        self.d.zeroize();
+    }
+}
+
+impl Utilities {
+    // Check the `kty` and `crv` members of a JWK against the curve of the key type
+    fn check_jwk(kty: &str, crv: &str, curve: JwkCurve) -> UtilitiesResult<()> {
+        if kty != JWK_KEY_TYPE_OKP {
+            return Err(UtilitiesError::JwkUnsupportedKeyType {
+                key_type: kty.to_owned(),
+            });
+        }
+
+        match JwkCurve::from_name(crv)? == curve {
+            true => Ok(()),
+            false => Err(UtilitiesError::JwkUnsupportedCurve {
+                curve: crv.to_owned(),
+            }),
+        }
+    }
+
+    // Encode a secret key as a JWK JSON string including the private `d` member
+    #[cfg(any(feature = "ed25519", feature = "x25519"))]
+    fn secret_to_jwk(curve: JwkCurve, public_key: &[u8; 32], secret: &Secret32Bytes) -> String {
+        let jwk = Jwk::new(curve, public_key);
+        let secret_jwk = SecretJwk {
+            kty: jwk.kty,
+            crv: jwk.crv,
+            x: jwk.x,
+            d: Some(Utilities::bytes_to_base64(
+                &secret.0,
+                Base64Variant::UrlSafeNoPad,
+            )),
+        };
+
+        serde_json::to_string(&secret_jwk).unwrap() // Never fails since all the members are strings
+    }
+
+    // Decode the private `d` member and the public key of a JWK JSON string
+    #[cfg(any(feature = "ed25519", feature = "x25519"))]
+    fn secret_from_jwk(curve: JwkCurve, value: &str) -> UtilitiesResult<(Secret32Bytes, [u8; 32])> {
+        let secret_jwk: SecretJwk = match serde_json::from_str(value) {
+            Ok(secret_jwk) => secret_jwk,
+            Err(_) => return Err(UtilitiesError::JwkInvalidJson),
+        };
+
+        let public_key = Jwk {
+            kty: secret_jwk.kty.clone(),
+            crv: secret_jwk.crv.clone(),
+            x: secret_jwk.x.clone(),
+            kid: Option::default(),
+        }
+        .public_key(curve)?;
+
+        let d = match secret_jwk.d.as_deref() {
+            Some(d) => d,
+            None => return Err(UtilitiesError::JwkMissingPrivateKey),
+        };
+        let mut secret = Secret32Bytes::default();
+        Utilities::base64_to_buffer(d, Base64Variant::UrlSafeNoPad, &mut secret.0)?;
+
+        Ok((secret, public_key))
+    }
+}
+
+/// Implements JWK conversion for the public key types
+macro_rules! impl_public_jwk {
+    ($($data_type:ident => $curve:ident),+ $(,)?) => {
+        $(
+            impl $data_type {
+                /// Convert the public key to an `OKP` JWK
+                pub fn to_jwk(&self) -> Jwk {
+                    Jwk::new(JwkCurve::$curve, &self.0)
+                }
+
+                /// Convert an `OKP` JWK to the public key,
+                /// rejecting a key type or curve that does not match
+                pub fn from_jwk(jwk: &Jwk) -> UtilitiesResult<Self> {
+                    Ok($data_type(jwk.public_key(JwkCurve::$curve)?))
+                }
+
+                /// The RFC 7638 thumbprint of the JWK of the public key
+                pub fn jwk_thumbprint(&self) -> String {
+                    self.to_jwk().thumbprint()
+                }
+            }
+        )+
+    };
+}
+
+impl_public_jwk!(
+    Ed25519Public => Ed25519,
+    X25519Public => X25519,
+);
+
+#[cfg(feature = "ed25519")]
+impl Ed25519Vault {
+    /// Create a vault from an `OKP` JWK JSON string with the private `d` member.
+    /// Returns `UtilitiesError::JwkPublicKeyMismatch` if `x` is not the public key of `d`
+    pub fn from_jwk(value: &str) -> UtilitiesResult<Self> {
+        let (secret, public_key) = Utilities::secret_from_jwk(JwkCurve::Ed25519, value)?;
+        let vault = Ed25519Vault::from_seed(&secret)?;
+
+        match vault.public().0 == public_key {
+            true => Ok(vault),
+            false => Err(UtilitiesError::JwkPublicKeyMismatch),
+        }
+    }
+
+    /// Export the keypair as an `OKP` JWK JSON string including the private `d` member.
+    /// The returned string contains the secret key in plain text, use `Ed25519Public::to_jwk`
+    /// to export only the public key
+    pub fn dangerous_export_jwk(&self) -> String {
+        Utilities::secret_to_jwk(JwkCurve::Ed25519, &self.public().0, &self.secret())
+    }
+}
+
+#[cfg(feature = "x25519")]
+impl X25519Secret {
+    /// Create an X25519 secret key from an `OKP` JWK JSON string with the private `d` member.
+    /// Returns `UtilitiesError::JwkPublicKeyMismatch` if `x` is not the public key of `d`
+    pub fn from_jwk(value: &str) -> UtilitiesResult<Self> {
+        let (secret, public_key) = Utilities::secret_from_jwk(JwkCurve::X25519, value)?;
+        let x25519_secret = X25519Secret::from_secret(&secret);
+
+        match x25519_secret.public().0 == public_key {
+            true => Ok(x25519_secret),
+            false => Err(UtilitiesError::JwkPublicKeyMismatch),
+        }
+    }
+
+    /// Export the secret key as an `OKP` JWK JSON string including the private `d` member.
+    /// The returned string contains the secret key in plain text, use `X25519Public::to_jwk`
+    /// to export only the public key
+    pub fn dangerous_export_jwk(&self) -> String {
+        Utilities::secret_to_jwk(JwkCurve::X25519, &self.public().0, &self.secret())
+    }
+}
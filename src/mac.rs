@@ -0,0 +1,93 @@
+use crate::{Secret32Bytes, SecretVec, Utilities, UtilitiesError, UtilitiesResult};
+
+#[cfg(feature = "blake3")]
+use crate::Blake3Hash;
+use crate::MacTag32;
+#[cfg(feature = "hmac")]
+use crate::MacTag64;
+
+#[cfg(feature = "hmac")]
+use hmac::{Hmac, Mac};
+#[cfg(feature = "hmac")]
+use sha2::{Sha256, Sha512};
+
+/// The secret types that can be used as the key of a message authentication code
+pub trait MacKey {
+    /// The bytes of the secret key
+    fn mac_key_bytes(&self) -> &[u8];
+}
+
+impl MacKey for Secret32Bytes {
+    fn mac_key_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl MacKey for SecretVec {
+    fn mac_key_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Utilities {
+    /// Compute the HMAC-SHA256 tag of a message
+    #[cfg(feature = "hmac")]
+    pub fn hmac_sha256(key: &impl MacKey, message: &[u8]) -> MacTag32 {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key.mac_key_bytes()).unwrap(); // Never fails since HMAC accepts keys of any length
+        mac.update(message);
+
+        MacTag32(mac.finalize().into_bytes().into())
+    }
+
+    /// Verify the HMAC-SHA256 tag of a message in constant time
+    #[cfg(feature = "hmac")]
+    pub fn hmac_sha256_verify(
+        key: &impl MacKey,
+        message: &[u8],
+        tag: &MacTag32,
+    ) -> UtilitiesResult<()> {
+        Utilities::verify_mac(&Utilities::hmac_sha256(key, message).0, &tag.0)
+    }
+
+    /// Compute the HMAC-SHA512 tag of a message
+    #[cfg(feature = "hmac")]
+    pub fn hmac_sha512(key: &impl MacKey, message: &[u8]) -> MacTag64 {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key.mac_key_bytes()).unwrap(); // Never fails since HMAC accepts keys of any length
+        mac.update(message);
+
+        MacTag64(mac.finalize().into_bytes().into())
+    }
+
+    /// Verify the HMAC-SHA512 tag of a message in constant time
+    #[cfg(feature = "hmac")]
+    pub fn hmac_sha512_verify(
+        key: &impl MacKey,
+        message: &[u8],
+        tag: &MacTag64,
+    ) -> UtilitiesResult<()> {
+        Utilities::verify_mac(&Utilities::hmac_sha512(key, message).0, &tag.0)
+    }
+
+    /// Compute the tag of a message using Blake3 in keyed mode
+    #[cfg(feature = "blake3")]
+    pub fn blake3_mac(key: &Secret32Bytes, message: &[u8]) -> MacTag32 {
+        MacTag32(Blake3Hash::keyed_hash(key, message).0)
+    }
+
+    /// Verify the Blake3 keyed mode tag of a message in constant time
+    #[cfg(feature = "blake3")]
+    pub fn blake3_mac_verify(
+        key: &Secret32Bytes,
+        message: &[u8],
+        tag: &MacTag32,
+    ) -> UtilitiesResult<()> {
+        Utilities::verify_mac(&Utilities::blake3_mac(key, message).0, &tag.0)
+    }
+
+    fn verify_mac<const N: usize>(computed: &[u8; N], tag: &[u8; N]) -> UtilitiesResult<()> {
+        match constant_time_eq::constant_time_eq_n(computed, tag) {
+            true => Ok(()),
+            false => Err(UtilitiesError::InvalidMac),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Blake3Hash, Blake3Hasher, UtilitiesError, UtilitiesResult};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// The prefix added to the bytes of a leaf before hashing
+/// to separate the hashes of leaves from the hashes of nodes
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+/// The prefix added to the hashes of two child nodes before hashing
+/// to separate the hashes of nodes from the hashes of leaves
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// A binary Merkle tree whose leaves and nodes are hashed using Blake3.
+/// Leaves are hashed as `Blake3(0x00 || leaf)` and nodes as `Blake3(0x01 || left || right)`
+/// which prevents second preimage attacks where a node is presented as a leaf.
+/// If a level has an odd number of nodes, the last node is promoted to the next level
+/// instead of being hashed with a duplicate of itself.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct MerkleTree {
+    levels: Vec<Vec<Blake3Hash>>,
+}
+
+impl MerkleTree {
+    /// Build a Merkle tree from the bytes of the leaves
+    pub fn new<T: AsRef<[u8]>>(leaves: &[T]) -> Self {
+        MerkleTree::from_leaf_hashes(
+            leaves
+                .iter()
+                .map(|leaf| MerkleTree::hash_leaf(leaf.as_ref()))
+                .collect(),
+        )
+    }
+
+    /// Build a Merkle tree from leaves that have already been hashed using `MerkleTree::hash_leaf`
+    pub fn from_leaf_hashes(leaf_hashes: Vec<Blake3Hash>) -> Self {
+        let mut levels = vec![leaf_hashes];
+
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => MerkleTree::hash_node(left, right),
+                    _ => pair[0],
+                })
+                .collect();
+            levels.push(level);
+        }
+
+        MerkleTree { levels }
+    }
+
+    /// Hash the bytes of a leaf as `Blake3(0x00 || leaf)`
+    pub fn hash_leaf(leaf: &[u8]) -> Blake3Hash {
+        Blake3Hasher::new()
+            .update(&[MERKLE_LEAF_PREFIX])
+            .update(leaf)
+            .finalize()
+    }
+
+    /// Hash two child nodes as `Blake3(0x01 || left || right)`
+    pub fn hash_node(left: &Blake3Hash, right: &Blake3Hash) -> Blake3Hash {
+        Blake3Hasher::new()
+            .update(&[MERKLE_NODE_PREFIX])
+            .update(&left.0)
+            .update(&right.0)
+            .finalize()
+    }
+
+    /// The root of the tree. The root of a tree without leaves is `Blake3Hash::default()`
+    pub fn root(&self) -> Blake3Hash {
+        match self.levels[self.levels.len() - 1].first() {
+            Some(root) => *root,
+            None => Blake3Hash::default(),
+        }
+    }
+
+    /// The number of leaves in the tree
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Check whether the tree has no leaves
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Create a proof that the leaf at `index` is included in the tree.
+    /// Returns `None` if the index is out of bounds
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut position = index;
+        let mut siblings = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                siblings.push(level[sibling]);
+            }
+            position /= 2;
+        }
+
+        Some(MerkleProof {
+            index: index as u64,
+            leaf_count: self.len() as u64,
+            siblings,
+        })
+    }
+}
+
+/// A compact proof that a leaf is included in a `MerkleTree`.
+/// Only the sibling hashes are stored since the position of each sibling
+/// is derived from the index of the leaf and the number of leaves in the tree
+#[derive(Clone, PartialEq, Eq, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct MerkleProof {
+    /// The index of the leaf in the tree
+    pub index: u64,
+    /// The number of leaves in the tree
+    pub leaf_count: u64,
+    /// The hashes of the siblings from the leaf level up to the level below the root
+    pub siblings: Vec<Blake3Hash>,
+}
+
+impl MerkleProof {
+    /// Verify that the bytes of a leaf are included in the tree with the given root
+    pub fn verify(&self, root: &Blake3Hash, leaf: &[u8]) -> UtilitiesResult<()> {
+        self.verify_leaf_hash(root, &MerkleTree::hash_leaf(leaf))
+    }
+
+    /// Verify that a leaf hashed using `MerkleTree::hash_leaf`
+    /// is included in the tree with the given root
+    pub fn verify_leaf_hash(
+        &self,
+        root: &Blake3Hash,
+        leaf_hash: &Blake3Hash,
+    ) -> UtilitiesResult<()> {
+        match self.compute_root(leaf_hash) {
+            Some(computed_root) if &computed_root == root => Ok(()),
+            _ => Err(UtilitiesError::InvalidMerkleProof),
+        }
+    }
+
+    fn compute_root(&self, leaf_hash: &Blake3Hash) -> Option<Blake3Hash> {
+        if self.index >= self.leaf_count {
+            return None;
+        }
+
+        let mut siblings = self.siblings.iter();
+        let mut position = self.index;
+        let mut width = self.leaf_count;
+        let mut hash = *leaf_hash;
+
+        while width > 1 {
+            if position % 2 == 1 {
+                hash = MerkleTree::hash_node(siblings.next()?, &hash);
+            } else if position + 1 < width {
+                hash = MerkleTree::hash_node(&hash, siblings.next()?);
+            }
+            position /= 2;
+            width = width.div_ceil(2);
+        }
+
+        match siblings.next() {
+            Some(_) => None,
+            None => Some(hash),
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{Blake3Hash, Blake3Hasher, MerkleTree, UtilitiesError, UtilitiesResult};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// The prefix added to the number of leaves and the peaks of a `MerkleMountainRange`
+/// when bagging the peaks to separate the root from the hashes of leaves and nodes
+pub const MMR_ROOT_PREFIX: u8 = 0x02;
+
+/// An append-only Merkle Mountain Range whose leaves and nodes are hashed like a `MerkleTree`.
+/// The leaves are grouped into perfect binary trees, the peaks, one for each bit set
+/// in the number of leaves, from the tallest peak on the left to the shortest on the right.
+/// The peaks are bagged into the root as `Blake3(0x02 || leaf_count as u64 LE || peaks)`
+/// so roots of ranges with a different number of leaves never collide
+#[derive(Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct MerkleMountainRange {
+    // `levels[height][index]` is the root of the perfect tree of `2^height` leaves
+    // starting at leaf `index * 2^height`
+    levels: Vec<Vec<Blake3Hash>>,
+}
+
+impl MerkleMountainRange {
+    /// Create an empty Merkle Mountain Range
+    pub fn new() -> Self {
+        MerkleMountainRange::default()
+    }
+
+    /// Append the bytes of a leaf returning the index of the leaf
+    pub fn push(&mut self, leaf: &[u8]) -> u64 {
+        self.push_leaf_hash(MerkleTree::hash_leaf(leaf))
+    }
+
+    /// Append a leaf that has already been hashed using `MerkleTree::hash_leaf`
+    /// returning the index of the leaf
+    pub fn push_leaf_hash(&mut self, leaf_hash: Blake3Hash) -> u64 {
+        let index = self.len();
+        let mut hash = leaf_hash;
+        let mut height = 0usize;
+
+        loop {
+            if self.levels.len() == height {
+                self.levels.push(Vec::new());
+            }
+            self.levels[height].push(hash);
+
+            let level = &self.levels[height];
+            if level.len() % 2 == 1 {
+                break;
+            }
+            hash = MerkleTree::hash_node(&level[level.len() - 2], &level[level.len() - 1]);
+            height += 1;
+        }
+
+        index
+    }
+
+    /// The number of leaves in the range
+    pub fn len(&self) -> u64 {
+        match self.levels.first() {
+            Some(leaves) => leaves.len() as u64,
+            None => 0,
+        }
+    }
+
+    /// Check whether the range has no leaves
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The peaks of the range, from the tallest to the shortest
+    pub fn peaks(&self) -> Vec<Blake3Hash> {
+        self.peaks_at(self.len()).unwrap_or_default()
+    }
+
+    /// The root of the range
+    pub fn root(&self) -> Blake3Hash {
+        MerkleMountainRange::bag_peaks(self.len(), &self.peaks())
+    }
+
+    /// The root of the range when it had `leaf_count` leaves.
+    /// Returns `None` if the range has fewer leaves
+    pub fn root_at(&self, leaf_count: u64) -> Option<Blake3Hash> {
+        self.peaks_at(leaf_count)
+            .map(|peaks| MerkleMountainRange::bag_peaks(leaf_count, &peaks))
+    }
+
+    /// Bag the peaks of a range with `leaf_count` leaves into its root
+    pub fn bag_peaks(leaf_count: u64, peaks: &[Blake3Hash]) -> Blake3Hash {
+        let mut hasher = Blake3Hasher::new();
+        hasher
+            .update(&[MMR_ROOT_PREFIX])
+            .update(&leaf_count.to_le_bytes());
+        peaks.iter().for_each(|peak| {
+            hasher.update(&peak.0);
+        });
+
+        hasher.finalize()
+    }
+
+    /// Create a proof that the leaf at `index` is included in the range
+    /// when the range has its current number of leaves.
+    /// Returns `None` if the index is out of bounds
+    pub fn proof(&self, index: u64) -> Option<MmrProof> {
+        let leaf_count = self.len();
+        if index >= leaf_count {
+            return None;
+        }
+
+        let (peak_position, _, peak_height) = MerkleMountainRange::find_peak(leaf_count, index)?;
+        let siblings = (0..peak_height)
+            .map(|height| self.node(height, (index >> height) ^ 1))
+            .collect::<Option<Vec<Blake3Hash>>>()?;
+        let mut peaks = self.peaks_at(leaf_count)?;
+        peaks.remove(peak_position);
+
+        Some(MmrProof {
+            leaf_index: index,
+            leaf_count,
+            siblings,
+            peaks,
+        })
+    }
+
+    /// Create a proof that the range with `old_leaf_count` leaves
+    /// is a prefix of the range with its current number of leaves.
+    /// Returns `None` if the range has fewer than `old_leaf_count` leaves
+    pub fn consistency_proof(&self, old_leaf_count: u64) -> Option<MmrConsistencyProof> {
+        let new_leaf_count = self.len();
+        if old_leaf_count > new_leaf_count {
+            return None;
+        }
+
+        let old_peaks = self.peaks_at(old_leaf_count)?;
+        let mut hashes = Vec::new();
+
+        if old_leaf_count < new_leaf_count {
+            // The tallest peak of the new range that is not a peak of the old range
+            let split_height = 63 - (old_leaf_count ^ new_leaf_count).leading_zeros() as usize;
+            let split_offset = MerkleMountainRange::high_bits(new_leaf_count, split_height);
+
+            if old_leaf_count > split_offset {
+                // Climb from the shortest old peak up to the split peak collecting the
+                // right siblings, the left siblings being the other old peaks
+                let lowest_height = old_leaf_count.trailing_zeros() as usize;
+                let mut index = (old_leaf_count >> lowest_height) - 1;
+                for height in lowest_height..split_height {
+                    if index.is_multiple_of(2) {
+                        hashes.push(self.node(height, index + 1)?);
+                    }
+                    index /= 2;
+                }
+            } else {
+                hashes.push(self.node(split_height, split_offset >> split_height)?);
+            }
+
+            let new_peaks = self.peaks_at(new_leaf_count)?;
+            let split_position =
+                MerkleMountainRange::high_bits(new_leaf_count, split_height).count_ones() as usize;
+            hashes.extend_from_slice(&new_peaks[split_position + 1..]);
+        }
+
+        Some(MmrConsistencyProof {
+            old_leaf_count,
+            new_leaf_count,
+            old_peaks,
+            hashes,
+        })
+    }
+
+    fn node(&self, height: usize, index: u64) -> Option<Blake3Hash> {
+        self.levels.get(height)?.get(index as usize).copied()
+    }
+
+    fn peaks_at(&self, leaf_count: u64) -> Option<Vec<Blake3Hash>> {
+        if leaf_count > self.len() {
+            return None;
+        }
+
+        MerkleMountainRange::peak_ranges(leaf_count)
+            .map(|(offset, height)| self.node(height, offset >> height))
+            .collect()
+    }
+
+    // The offset of the first leaf and the height of each peak of a range with `leaf_count` leaves
+    fn peak_ranges(leaf_count: u64) -> impl Iterator<Item = (u64, usize)> {
+        (0..64usize)
+            .rev()
+            .filter(move |height| (leaf_count >> height) & 1 == 1)
+            .map(move |height| (MerkleMountainRange::high_bits(leaf_count, height), height))
+    }
+
+    // Clear the bits of `value` at `height` and below
+    fn high_bits(value: u64, height: usize) -> u64 {
+        value & !(u64::MAX >> (63 - height))
+    }
+
+    // The position, offset of the first leaf and height of the peak containing the leaf at `index`
+    fn find_peak(leaf_count: u64, index: u64) -> Option<(usize, u64, usize)> {
+        MerkleMountainRange::peak_ranges(leaf_count)
+            .enumerate()
+            .find(|(_, (offset, height))| index >= *offset && index - offset < 1 << height)
+            .map(|(position, (offset, height))| (position, offset, height))
+    }
+}
+
+/// A proof that a leaf is included in a `MerkleMountainRange` with `leaf_count` leaves
+#[derive(Clone, PartialEq, Eq, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct MmrProof {
+    /// The index of the leaf in the range
+    pub leaf_index: u64,
+    /// The number of leaves in the range
+    pub leaf_count: u64,
+    /// The hashes of the siblings from the leaf up to the level below its peak
+    pub siblings: Vec<Blake3Hash>,
+    /// The peaks of the range except the peak containing the leaf
+    pub peaks: Vec<Blake3Hash>,
+}
+
+impl MmrProof {
+    /// Verify that the bytes of a leaf are included in the range with the given root
+    pub fn verify(&self, root: &Blake3Hash, leaf: &[u8]) -> UtilitiesResult<()> {
+        self.verify_leaf_hash(root, &MerkleTree::hash_leaf(leaf))
+    }
+
+    /// Verify that a leaf hashed using `MerkleTree::hash_leaf`
+    /// is included in the range with the given root
+    pub fn verify_leaf_hash(
+        &self,
+        root: &Blake3Hash,
+        leaf_hash: &Blake3Hash,
+    ) -> UtilitiesResult<()> {
+        match self.compute_root(leaf_hash) {
+            Some(computed_root) if &computed_root == root => Ok(()),
+            _ => Err(UtilitiesError::InvalidMerkleProof),
+        }
+    }
+
+    fn compute_root(&self, leaf_hash: &Blake3Hash) -> Option<Blake3Hash> {
+        let (peak_position, peak_offset, peak_height) =
+            MerkleMountainRange::find_peak(self.leaf_count, self.leaf_index)?;
+        if self.siblings.len() != peak_height
+            || self.peaks.len() + 1 != self.leaf_count.count_ones() as usize
+        {
+            return None;
+        }
+
+        let position = self.leaf_index - peak_offset;
+        let peak = self
+            .siblings
+            .iter()
+            .enumerate()
+            .fold(*leaf_hash, |hash, (height, sibling)| {
+                if (position >> height) & 1 == 1 {
+                    MerkleTree::hash_node(sibling, &hash)
+                } else {
+                    MerkleTree::hash_node(&hash, sibling)
+                }
+            });
+
+        let mut peaks = self.peaks.clone();
+        peaks.insert(peak_position, peak);
+
+        Some(MerkleMountainRange::bag_peaks(self.leaf_count, &peaks))
+    }
+}
+
+/// A proof that a `MerkleMountainRange` with `old_leaf_count` leaves is a prefix
+/// of the same range after it grew to `new_leaf_count` leaves
+#[derive(Clone, PartialEq, Eq, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct MmrConsistencyProof {
+    /// The number of leaves in the old range
+    pub old_leaf_count: u64,
+    /// The number of leaves in the new range
+    pub new_leaf_count: u64,
+    /// The peaks of the old range
+    pub old_peaks: Vec<Blake3Hash>,
+    /// The right siblings needed to grow the old peaks into a peak of the new range
+    /// followed by the peaks of the new range that contain no leaves of the old range
+    pub hashes: Vec<Blake3Hash>,
+}
+
+impl MmrConsistencyProof {
+    /// Verify that the range with the old root is a prefix of the range with the new root
+    pub fn verify(&self, old_root: &Blake3Hash, new_root: &Blake3Hash) -> UtilitiesResult<()> {
+        match self.compute_new_root(old_root) {
+            Some(computed_root) if &computed_root == new_root => Ok(()),
+            _ => Err(UtilitiesError::InvalidMmrConsistencyProof),
+        }
+    }
+
+    fn compute_new_root(&self, old_root: &Blake3Hash) -> Option<Blake3Hash> {
+        let old_leaf_count = self.old_leaf_count;
+        let new_leaf_count = self.new_leaf_count;
+
+        if old_leaf_count > new_leaf_count
+            || self.old_peaks.len() != old_leaf_count.count_ones() as usize
+            || &MerkleMountainRange::bag_peaks(old_leaf_count, &self.old_peaks) != old_root
+        {
+            return None;
+        }
+
+        if old_leaf_count == new_leaf_count {
+            return match self.hashes.is_empty() {
+                true => Some(*old_root),
+                false => None,
+            };
+        }
+
+        let split_height = 63 - (old_leaf_count ^ new_leaf_count).leading_zeros() as usize;
+        let split_offset = MerkleMountainRange::high_bits(new_leaf_count, split_height);
+        // The old peaks taller than the split height are also peaks of the new range
+        let shared_count =
+            MerkleMountainRange::high_bits(old_leaf_count, split_height).count_ones() as usize;
+        let mut new_peaks = self.old_peaks[..shared_count].to_vec();
+        let mut hashes = self.hashes.iter();
+
+        if old_leaf_count > split_offset {
+            let mut old_peaks = self.old_peaks[shared_count..].iter().rev();
+            let lowest_height = old_leaf_count.trailing_zeros() as usize;
+            let mut index = (old_leaf_count >> lowest_height) - 1;
+            let mut hash = *old_peaks.next()?;
+
+            for _ in lowest_height..split_height {
+                hash = if index.is_multiple_of(2) {
+                    MerkleTree::hash_node(&hash, hashes.next()?)
+                } else {
+                    MerkleTree::hash_node(old_peaks.next()?, &hash)
+                };
+                index /= 2;
+            }
+
+            if old_peaks.next().is_some() {
+                return None;
+            }
+            new_peaks.push(hash);
+        }
+
+        new_peaks.extend(hashes);
+        if new_peaks.len() != new_leaf_count.count_ones() as usize {
+            return None;
+        }
+
+        Some(MerkleMountainRange::bag_peaks(new_leaf_count, &new_peaks))
+    }
+}
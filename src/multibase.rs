@@ -0,0 +1,196 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Base32Variant, Base64Variant, Blake3Hash, Ed25519Public,
+    Ed25519Signature, HexVec, Keccak256Hash, MacTag32, MacTag64, Sha256Hash, Sha3_256Hash,
+    Sha512Hash, Sr25519Public, Sr25519Signature, TaiTimestamp, Utilities, UtilitiesError,
+    UtilitiesResult, X25519Public,
+};
+
+/// The encodings of a multibase string, each identified by the prefix character of the string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multibase {
+    /// Lowercase hex with the prefix `f`
+    Base16,
+    /// Uppercase hex with the prefix `F`
+    Base16Upper,
+    /// Lowercase RFC 4648 base32 without padding with the prefix `b`
+    Base32,
+    /// Uppercase RFC 4648 base32 without padding with the prefix `B`
+    Base32Upper,
+    /// Lowercase RFC 4648 base32 with padding with the prefix `c`
+    Base32Pad,
+    /// Uppercase RFC 4648 base32 with padding with the prefix `C`
+    Base32PadUpper,
+    /// z-base-32 with the prefix `h`
+    Base32Z,
+    /// Bitcoin base58 with the prefix `z`
+    Base58Btc,
+    /// Standard base64 without padding with the prefix `m`
+    Base64,
+    /// Standard base64 with padding with the prefix `M`
+    Base64Pad,
+    /// URL safe base64 without padding with the prefix `u`
+    Base64Url,
+    /// URL safe base64 with padding with the prefix `U`
+    Base64UrlPad,
+}
+
+impl Multibase {
+    /// The prefix character of the encoding
+    pub fn prefix(&self) -> char {
+        match self {
+            Multibase::Base16 => 'f',
+            Multibase::Base16Upper => 'F',
+            Multibase::Base32 => 'b',
+            Multibase::Base32Upper => 'B',
+            Multibase::Base32Pad => 'c',
+            Multibase::Base32PadUpper => 'C',
+            Multibase::Base32Z => 'h',
+            Multibase::Base58Btc => 'z',
+            Multibase::Base64 => 'm',
+            Multibase::Base64Pad => 'M',
+            Multibase::Base64Url => 'u',
+            Multibase::Base64UrlPad => 'U',
+        }
+    }
+
+    /// Get the encoding identified by a prefix character
+    pub fn from_prefix(prefix: char) -> UtilitiesResult<Self> {
+        match prefix {
+            'f' => Ok(Multibase::Base16),
+            'F' => Ok(Multibase::Base16Upper),
+            'b' => Ok(Multibase::Base32),
+            'B' => Ok(Multibase::Base32Upper),
+            'c' => Ok(Multibase::Base32Pad),
+            'C' => Ok(Multibase::Base32PadUpper),
+            'h' => Ok(Multibase::Base32Z),
+            'z' => Ok(Multibase::Base58Btc),
+            'm' => Ok(Multibase::Base64),
+            'M' => Ok(Multibase::Base64Pad),
+            'u' => Ok(Multibase::Base64Url),
+            'U' => Ok(Multibase::Base64UrlPad),
+            _ => Err(UtilitiesError::MultibaseUnsupportedPrefix {
+                prefix: prefix.to_string(),
+            }),
+        }
+    }
+}
+
+impl Utilities {
+    /// Encode bytes to a multibase string which is the prefix character of the encoding
+    /// followed by the encoded bytes
+    pub fn bytes_to_multibase(bytes: &[u8], base: Multibase) -> String {
+        let encoded = match base {
+            Multibase::Base16 => hex::encode(bytes),
+            Multibase::Base16Upper => hex::encode_upper(bytes),
+            Multibase::Base32 => {
+                Utilities::bytes_to_base32(bytes, Base32Variant::Rfc4648LowerNoPad)
+            }
+            Multibase::Base32Upper => {
+                Utilities::bytes_to_base32(bytes, Base32Variant::Rfc4648NoPad)
+            }
+            Multibase::Base32Pad => {
+                Utilities::bytes_to_base32(bytes, Base32Variant::Rfc4648).to_lowercase()
+            }
+            Multibase::Base32PadUpper => Utilities::bytes_to_base32(bytes, Base32Variant::Rfc4648),
+            Multibase::Base32Z => Utilities::bytes_to_base32(bytes, Base32Variant::ZBase32),
+            Multibase::Base58Btc => bs58::encode(bytes).into_string(),
+            Multibase::Base64 => Utilities::bytes_to_base64(bytes, Base64Variant::StandardNoPad),
+            Multibase::Base64Pad => Utilities::bytes_to_base64(bytes, Base64Variant::Standard),
+            Multibase::Base64Url => Utilities::bytes_to_base64(bytes, Base64Variant::UrlSafeNoPad),
+            Multibase::Base64UrlPad => Utilities::bytes_to_base64(bytes, Base64Variant::UrlSafe),
+        };
+
+        format!("{}{}", base.prefix(), encoded)
+    }
+
+    /// Decode a multibase string detecting the encoding from its prefix character
+    pub fn multibase_to_bytes(value: &str) -> UtilitiesResult<(Multibase, Vec<u8>)> {
+        let mut chars = value.chars();
+        let base = match chars.next() {
+            Some(prefix) => Multibase::from_prefix(prefix)?,
+            None => return Err(UtilitiesError::MultibaseEmptyString),
+        };
+        let encoded = chars.as_str();
+
+        let bytes = match base {
+            Multibase::Base16 | Multibase::Base16Upper => Utilities::hex_to_bytes(encoded)?,
+            Multibase::Base32 => {
+                Utilities::base32_to_bytes(encoded, Base32Variant::Rfc4648LowerNoPad)?
+            }
+            Multibase::Base32Upper => {
+                Utilities::base32_to_bytes(encoded, Base32Variant::Rfc4648NoPad)?
+            }
+            Multibase::Base32Pad => {
+                Utilities::base32_to_bytes(&encoded.to_uppercase(), Base32Variant::Rfc4648)?
+            }
+            Multibase::Base32PadUpper => {
+                Utilities::base32_to_bytes(encoded, Base32Variant::Rfc4648)?
+            }
+            Multibase::Base32Z => Utilities::base32_to_bytes(encoded, Base32Variant::ZBase32)?,
+            Multibase::Base58Btc => Utilities::base58_to_bytes(encoded)?,
+            Multibase::Base64 => Utilities::base64_to_bytes(encoded, Base64Variant::StandardNoPad)?,
+            Multibase::Base64Pad => Utilities::base64_to_bytes(encoded, Base64Variant::Standard)?,
+            Multibase::Base64Url => {
+                Utilities::base64_to_bytes(encoded, Base64Variant::UrlSafeNoPad)?
+            }
+            Multibase::Base64UrlPad => Utilities::base64_to_bytes(encoded, Base64Variant::UrlSafe)?,
+        };
+
+        Ok((base, bytes))
+    }
+}
+
+/// Implements `to_multibase` and `from_multibase` for the fixed size data types that are not secrets.
+/// Decoding returns `UtilitiesError::InvalidLength` if the decoded length is not the size of the type
+macro_rules! impl_multibase {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl $data_type {
+                /// Return the multibase representation of the bytes
+                pub fn to_multibase(&self, base: Multibase) -> String {
+                    Utilities::bytes_to_multibase(&self.0, base)
+                }
+
+                /// Decode a multibase string in any of the supported encodings
+                pub fn from_multibase(value: &str) -> UtilitiesResult<Self> {
+                    let (_, bytes) = Utilities::multibase_to_bytes(value)?;
+
+                    $data_type::try_from(&bytes[..])
+                }
+            }
+        )+
+    };
+}
+
+impl_multibase!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+);
+
+impl HexVec {
+    /// Return the multibase representation of the bytes
+    pub fn to_multibase(&self, base: Multibase) -> String {
+        Utilities::bytes_to_multibase(&self.0, base)
+    }
+
+    /// Decode a multibase string in any of the supported encodings
+    pub fn from_multibase(value: &str) -> UtilitiesResult<Self> {
+        let (_, bytes) = Utilities::multibase_to_bytes(value)?;
+
+        Ok(HexVec(bytes))
+    }
+}
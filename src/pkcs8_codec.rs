@@ -0,0 +1,349 @@
+use crate::{Ed25519Public, SecretVec, Utilities, UtilitiesError, UtilitiesResult, X25519Public};
+use pkcs8::{
+    der::{
+        asn1::BitStringRef,
+        pem::{self, LineEnding},
+        Decode, Encode,
+    },
+    spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef},
+    ObjectIdentifier,
+};
+
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+use crate::Secret32Bytes;
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+use pkcs8::{
+    der::asn1::OctetStringRef,
+    pkcs5::{self, pbes2},
+    EncryptedPrivateKeyInfo, PrivateKeyInfo,
+};
+
+#[cfg(feature = "ed25519")]
+use crate::Ed25519Vault;
+#[cfg(feature = "x25519")]
+use crate::X25519Secret;
+
+/// The algorithm identifier of Ed25519 keys (RFC 8410)
+pub const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+/// The algorithm identifier of X25519 keys (RFC 8410)
+pub const X25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.110");
+/// The PEM label of a `SubjectPublicKeyInfo` document
+pub const PEM_PUBLIC_KEY_LABEL: &str = "PUBLIC KEY";
+/// The PEM label of an unencrypted PKCS#8 document
+pub const PEM_PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+/// The PEM label of an encrypted PKCS#8 document
+pub const PEM_ENCRYPTED_PRIVATE_KEY_LABEL: &str = "ENCRYPTED PRIVATE KEY";
+/// The default number of PBKDF2-SHA256 iterations used to encrypt a PKCS#8 document
+pub const PBES2_DEFAULT_ITERATIONS: u32 = 600_000;
+
+/// The version of a PKCS#8 document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Pkcs8Version {
+    /// `PrivateKeyInfo` (RFC 5208) containing only the secret key
+    V1,
+    /// `OneAsymmetricKey` (RFC 5958) containing the secret key and the public key
+    V2,
+}
+
+/// The PBES2 parameters used to encrypt a PKCS#8 document
+/// using PBKDF2-SHA256 and AES-256-CBC. The salt and the IV must be random
+/// and must never be reused with the same password
+pub struct Pbes2Params {
+    /// The number of PBKDF2-SHA256 iterations
+    pub iterations: u32,
+    /// The PBKDF2 salt
+    pub salt: [u8; 16],
+    /// The AES-256-CBC initialization vector
+    pub iv: [u8; 16],
+}
+
+impl Pbes2Params {
+    /// Create the parameters from a salt and an IV using `PBES2_DEFAULT_ITERATIONS`
+    pub fn new(salt: [u8; 16], iv: [u8; 16]) -> Self {
+        Pbes2Params {
+            iterations: PBES2_DEFAULT_ITERATIONS,
+            salt,
+            iv,
+        }
+    }
+
+    /// Create the parameters from a random salt and IV generated using `ChaCha20`
+    /// using `PBES2_DEFAULT_ITERATIONS`
+    #[cfg(feature = "random")]
+    pub fn generate() -> Self {
+        let random = Utilities::rand32_chacha20();
+
+        Pbes2Params::new(
+            Utilities::to_byte_array(&random[..16]).unwrap(),
+            Utilities::to_byte_array(&random[16..]).unwrap(),
+        ) // Never fails since both halves are 16 bytes
+    }
+}
+
+impl Utilities {
+    // Check the algorithm identifier of a key against the expected algorithm.
+    // RFC 8410 requires the parameters to be absent
+    fn check_key_algorithm(
+        algorithm: &AlgorithmIdentifierRef<'_>,
+        oid: ObjectIdentifier,
+    ) -> UtilitiesResult<()> {
+        if algorithm.oid != oid {
+            return Err(UtilitiesError::KeyAlgorithmMismatch {
+                oid: algorithm.oid.to_string(),
+            });
+        }
+
+        match algorithm.parameters {
+            Some(_) => Err(UtilitiesError::KeyAlgorithmParametersPresent),
+            None => Ok(()),
+        }
+    }
+
+    // Decode a PEM document and check its label
+    fn pem_to_der(value: &str, label: &str) -> UtilitiesResult<SecretVec> {
+        let (pem_label, der) = pem::decode_vec(value.as_bytes())?;
+        let der = SecretVec(der);
+
+        match pem_label == label {
+            true => Ok(der),
+            false => Err(UtilitiesError::PemUnexpectedLabel {
+                label: pem_label.to_owned(),
+            }),
+        }
+    }
+
+    // Encode a DER document as PEM using `\n` line endings
+    fn der_to_pem(der: &[u8], label: &str) -> UtilitiesResult<String> {
+        Ok(pem::encode_string(label, LineEnding::LF, der)?)
+    }
+
+    // Encode a 32 byte public key as a `SubjectPublicKeyInfo` DER document
+    fn public_to_spki(oid: ObjectIdentifier, public_key: &[u8; 32]) -> UtilitiesResult<Vec<u8>> {
+        let spki = SubjectPublicKeyInfoRef {
+            algorithm: AlgorithmIdentifierRef {
+                oid,
+                parameters: None,
+            },
+            subject_public_key: BitStringRef::from_bytes(public_key)?,
+        };
+
+        Ok(spki.to_der()?)
+    }
+
+    // Decode the 32 byte public key of a `SubjectPublicKeyInfo` DER document
+    fn spki_to_public(oid: ObjectIdentifier, der: &[u8]) -> UtilitiesResult<[u8; 32]> {
+        let spki = SubjectPublicKeyInfoRef::from_der(der)?;
+        Utilities::check_key_algorithm(&spki.algorithm, oid)?;
+
+        match spki.subject_public_key.as_bytes() {
+            Some(public_key) => Utilities::to_byte_array(public_key),
+            None => Err(UtilitiesError::DerInvalidEncoding),
+        }
+    }
+
+    // Encode a 32 byte secret key as a PKCS#8 DER document,
+    // the secret key is wrapped in an OCTET STRING as required by RFC 8410
+    #[cfg(any(feature = "ed25519", feature = "x25519"))]
+    fn secret_to_pkcs8(
+        oid: ObjectIdentifier,
+        secret: &Secret32Bytes,
+        public_key: &[u8; 32],
+        version: Pkcs8Version,
+    ) -> UtilitiesResult<SecretVec> {
+        let private_key = SecretVec(OctetStringRef::new(&secret.0)?.to_der()?);
+
+        let mut private_key_info = PrivateKeyInfo::new(
+            AlgorithmIdentifierRef {
+                oid,
+                parameters: None,
+            },
+            &private_key.0,
+        );
+        if version == Pkcs8Version::V2 {
+            private_key_info.public_key = Some(public_key);
+        }
+
+        Ok(SecretVec(private_key_info.to_der()?))
+    }
+
+    // Decode the 32 byte secret key and, for a v2 document, the public key of a PKCS#8 DER document
+    #[cfg(any(feature = "ed25519", feature = "x25519"))]
+    fn pkcs8_to_secret(
+        oid: ObjectIdentifier,
+        der: &[u8],
+    ) -> UtilitiesResult<(Secret32Bytes, Option<[u8; 32]>)> {
+        let private_key_info = PrivateKeyInfo::from_der(der)?;
+        Utilities::check_key_algorithm(&private_key_info.algorithm, oid)?;
+
+        let private_key = match OctetStringRef::from_der(private_key_info.private_key) {
+            Ok(private_key) => private_key,
+            Err(_) => return Err(UtilitiesError::Pkcs8InvalidPrivateKey),
+        };
+        let secret = match private_key.as_bytes().len() {
+            32 => Secret32Bytes(Utilities::to_byte_array(private_key.as_bytes())?),
+            _ => return Err(UtilitiesError::Pkcs8InvalidPrivateKey),
+        };
+
+        let public_key = match private_key_info.public_key {
+            Some(public_key) => Some(Utilities::to_byte_array(public_key)?),
+            None => None,
+        };
+
+        Ok((secret, public_key))
+    }
+
+    // Encrypt a PKCS#8 DER document using PBES2 with PBKDF2-SHA256 and AES-256-CBC
+    #[cfg(any(feature = "ed25519", feature = "x25519"))]
+    fn encrypt_pkcs8(
+        der: &SecretVec,
+        password: &[u8],
+        params: &Pbes2Params,
+    ) -> UtilitiesResult<Vec<u8>> {
+        let parameters = pbes2::Parameters::pbkdf2_sha256_aes256cbc(
+            params.iterations,
+            &params.salt,
+            &params.iv,
+        )?;
+        let encrypted =
+            PrivateKeyInfo::from_der(&der.0)?.encrypt_with_params(parameters, password)?;
+
+        Ok(encrypted.as_bytes().to_vec())
+    }
+
+    // Decrypt an encrypted PKCS#8 DER document. `pkcs5` reports a padding error
+    // as `EncryptFailed` and a wrong password can also yield valid padding,
+    // so both cases and plaintext that is not a PKCS#8 document are decryption failures
+    #[cfg(any(feature = "ed25519", feature = "x25519"))]
+    fn decrypt_pkcs8(der: &[u8], password: &[u8]) -> UtilitiesResult<SecretVec> {
+        let decrypted = match EncryptedPrivateKeyInfo::from_der(der)?.decrypt(password) {
+            Ok(decrypted) => SecretVec(decrypted.as_bytes().to_vec()),
+            Err(pkcs8::Error::EncryptedPrivateKey(
+                pkcs5::Error::EncryptFailed | pkcs5::Error::DecryptFailed,
+            )) => return Err(UtilitiesError::Pkcs8DecryptionFailed),
+            Err(error) => return Err(error.into()),
+        };
+
+        match PrivateKeyInfo::from_der(&decrypted.0) {
+            Ok(_) => Ok(decrypted),
+            Err(_) => Err(UtilitiesError::Pkcs8DecryptionFailed),
+        }
+    }
+}
+
+/// Implements `SubjectPublicKeyInfo` DER and PEM conversion for the public key types
+macro_rules! impl_spki {
+    ($($data_type:ident => $oid:ident),+ $(,)?) => {
+        $(
+            impl $data_type {
+                /// Encode the public key as a `SubjectPublicKeyInfo` DER document
+                pub fn to_spki_der(&self) -> UtilitiesResult<Vec<u8>> {
+                    Utilities::public_to_spki($oid, &self.0)
+                }
+
+                /// Decode the public key from a `SubjectPublicKeyInfo` DER document
+                pub fn from_spki_der(der: &[u8]) -> UtilitiesResult<Self> {
+                    Ok($data_type(Utilities::spki_to_public($oid, der)?))
+                }
+
+                /// Encode the public key as a `SubjectPublicKeyInfo` PEM document
+                pub fn to_spki_pem(&self) -> UtilitiesResult<String> {
+                    Utilities::der_to_pem(&self.to_spki_der()?, PEM_PUBLIC_KEY_LABEL)
+                }
+
+                /// Decode the public key from a `SubjectPublicKeyInfo` PEM document
+                pub fn from_spki_pem(value: &str) -> UtilitiesResult<Self> {
+                    Self::from_spki_der(&Utilities::pem_to_der(value, PEM_PUBLIC_KEY_LABEL)?.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_spki!(
+    Ed25519Public => ED25519_OID,
+    X25519Public => X25519_OID,
+);
+
+/// Implements PKCS#8 DER and PEM conversion, including encrypted PKCS#8, for the secret key types
+macro_rules! impl_pkcs8 {
+    ($($feature:literal, $data_type:ident => $oid:ident, $from_secret:expr),+ $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl $data_type {
+                /// Encode the secret key as a PKCS#8 DER document.
+                /// The document contains the secret key in plain text
+                pub fn to_pkcs8_der(&self, version: Pkcs8Version) -> UtilitiesResult<SecretVec> {
+                    Utilities::secret_to_pkcs8($oid, &self.secret(), &self.public().0, version)
+                }
+
+                /// Decode the secret key from a PKCS#8 v1 or v2 DER document.
+                /// Returns `UtilitiesError::Pkcs8PublicKeyMismatch` if a v2 document
+                /// contains a public key that is not the public key of the secret key
+                pub fn from_pkcs8_der(der: &[u8]) -> UtilitiesResult<Self> {
+                    let (secret, public_key) = Utilities::pkcs8_to_secret($oid, der)?;
+                    let key: UtilitiesResult<Self> = $from_secret(&secret);
+                    let key = key?;
+
+                    match public_key {
+                        Some(public_key) if public_key != key.public().0 => {
+                            Err(UtilitiesError::Pkcs8PublicKeyMismatch)
+                        }
+                        _ => Ok(key),
+                    }
+                }
+
+                /// Encode the secret key as a PKCS#8 PEM document.
+                /// The document contains the secret key in plain text
+                pub fn to_pkcs8_pem(&self, version: Pkcs8Version) -> UtilitiesResult<String> {
+                    Utilities::der_to_pem(&self.to_pkcs8_der(version)?.0, PEM_PRIVATE_KEY_LABEL)
+                }
+
+                /// Decode the secret key from a PKCS#8 v1 or v2 PEM document
+                pub fn from_pkcs8_pem(value: &str) -> UtilitiesResult<Self> {
+                    Self::from_pkcs8_der(&Utilities::pem_to_der(value, PEM_PRIVATE_KEY_LABEL)?.0)
+                }
+
+                /// Encode the secret key as an encrypted PKCS#8 DER document using PBES2
+                pub fn to_pkcs8_encrypted_der(
+                    &self,
+                    version: Pkcs8Version,
+                    password: &[u8],
+                    params: &Pbes2Params,
+                ) -> UtilitiesResult<Vec<u8>> {
+                    Utilities::encrypt_pkcs8(&self.to_pkcs8_der(version)?, password, params)
+                }
+
+                /// Decode the secret key from an encrypted PKCS#8 DER document
+                pub fn from_pkcs8_encrypted_der(der: &[u8], password: &[u8]) -> UtilitiesResult<Self> {
+                    Self::from_pkcs8_der(&Utilities::decrypt_pkcs8(der, password)?.0)
+                }
+
+                /// Encode the secret key as an encrypted PKCS#8 PEM document using PBES2
+                pub fn to_pkcs8_encrypted_pem(
+                    &self,
+                    version: Pkcs8Version,
+                    password: &[u8],
+                    params: &Pbes2Params,
+                ) -> UtilitiesResult<String> {
+                    Utilities::der_to_pem(
+                        &self.to_pkcs8_encrypted_der(version, password, params)?,
+                        PEM_ENCRYPTED_PRIVATE_KEY_LABEL,
+                    )
+                }
+
+                /// Decode the secret key from an encrypted PKCS#8 PEM document
+                pub fn from_pkcs8_encrypted_pem(value: &str, password: &[u8]) -> UtilitiesResult<Self> {
+                    Self::from_pkcs8_encrypted_der(
+                        &Utilities::pem_to_der(value, PEM_ENCRYPTED_PRIVATE_KEY_LABEL)?.0,
+                        password,
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_pkcs8!(
+    "ed25519", Ed25519Vault => ED25519_OID, Ed25519Vault::from_seed,
+    "x25519", X25519Secret => X25519_OID, |secret: &Secret32Bytes| Ok(X25519Secret::from_secret(secret)),
+);
@@ -0,0 +1,170 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Blake3Hash, Ed25519Public, Ed25519Signature, HexVec,
+    Keccak256Hash, MacTag32, MacTag64, Sha256Hash, Sha3_256Hash, Sha512Hash, Sr25519Public,
+    Sr25519Signature, TaiTimestamp, UtilitiesResult, X25519Public,
+};
+use core::{fmt, str::FromStr};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use zeroize::Zeroizing;
+
+// Upper bound on the capacity preallocated from an untrusted sequence length hint
+const MAX_PREALLOCATED_BYTES: usize = 4096;
+
+#[cfg(feature = "serde_secret")]
+use crate::{Secret32Bytes, SecretVec, Utilities};
+
+// Serialize the bytes as a string in human readable formats and as raw bytes in binary formats.
+// The intermediate string is wiped since it may hold the encoding of a secret
+fn serialize_bytes<S: Serializer>(
+    serializer: S,
+    bytes: &[u8],
+    text: impl FnOnce() -> String,
+) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.serialize_str(&Zeroizing::new(text())),
+        false => serializer.serialize_bytes(bytes),
+    }
+}
+
+// Deserialize a string in human readable formats and raw bytes in binary formats.
+// The intermediate string and bytes are wiped since they may hold a secret
+fn deserialize_bytes<'de, D: Deserializer<'de>, T>(
+    deserializer: D,
+    from_text: impl FnOnce(&str) -> UtilitiesResult<T>,
+    from_bytes: impl FnOnce(&[u8]) -> UtilitiesResult<T>,
+) -> Result<T, D::Error> {
+    let outcome = match deserializer.is_human_readable() {
+        true => from_text(&Zeroizing::new(String::deserialize(deserializer)?)),
+        false => from_bytes(&Zeroizing::new(
+            deserializer.deserialize_byte_buf(BytesVisitor)?,
+        )),
+    };
+
+    outcome.map_err(|error| de::Error::custom(format!("{:?}", error)))
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+
+    // Some binary formats encode bytes as a sequence of integers
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(
+            seq.size_hint()
+                .unwrap_or_default()
+                .min(MAX_PREALLOCATED_BYTES),
+        );
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for the data types that are not secrets
+/// using their `fmt::Display` and `FromStr` representation in human readable formats
+macro_rules! impl_serde {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl Serialize for $data_type {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_bytes(serializer, &self.0, || self.to_string())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $data_type {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_bytes(deserializer, $data_type::from_str, |bytes| {
+                        $data_type::try_from(bytes)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_serde!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+);
+
+impl Serialize for HexVec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(serializer, &self.0, || self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HexVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer, HexVec::from_str, |bytes| {
+            Ok(HexVec::from(bytes))
+        })
+    }
+}
+
+#[cfg(feature = "serde_secret")]
+impl Serialize for Secret32Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(serializer, &self.0, || hex::encode(self.0))
+    }
+}
+
+#[cfg(feature = "serde_secret")]
+impl<'de> Deserialize<'de> for Secret32Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(
+            deserializer,
+            |value| Secret32Bytes::try_from(&Zeroizing::new(Utilities::hex_to_bytes(value)?)[..]),
+            |bytes| Secret32Bytes::try_from(bytes),
+        )
+    }
+}
+
+#[cfg(feature = "serde_secret")]
+impl Serialize for SecretVec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(serializer, &self.0, || hex::encode(&self.0))
+    }
+}
+
+#[cfg(feature = "serde_secret")]
+impl<'de> Deserialize<'de> for SecretVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(
+            deserializer,
+            |value| Ok(SecretVec(Utilities::hex_to_bytes(value)?)),
+            |bytes| Ok(SecretVec::from(bytes)),
+        )
+    }
+}
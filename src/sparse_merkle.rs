@@ -0,0 +1,269 @@
+use crate::{Blake3Hash, MerkleTree, UtilitiesError, UtilitiesResult};
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::collections::BTreeMap;
+
+/// The maximum depth of a `SparseMerkleTree` which is the number of bits in a `Blake3Hash` key
+pub const SPARSE_MERKLE_TREE_DEPTH: usize = 256;
+
+/// A sparse Merkle tree keyed by `Blake3Hash` where each key is the path from the root
+/// to its leaf, the most significant bit of the key choosing the child of the root.
+/// The hash of an empty subtree is `Blake3Hash::default()` and a subtree with a single leaf
+/// is represented by the hash of that leaf, so proofs only grow with the number of leaves
+/// and not with the 256 bit depth of the tree.
+/// Leaves are hashed as `Blake3(0x00 || key || Blake3(value))` and nodes as `Blake3(0x01 || left || right)`.
+/// The hashes of the nodes are cached and only the nodes on the path of a key
+/// are rehashed when the key is inserted or removed
+#[derive(Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct SparseMerkleTree {
+    leaves: BTreeMap<Blake3Hash, Blake3Hash>,
+    // The hashes of the subtrees with at least two leaves keyed by depth and path prefix
+    nodes: BTreeMap<(usize, Blake3Hash), Blake3Hash>,
+}
+
+impl SparseMerkleTree {
+    /// Create an empty sparse Merkle tree
+    pub fn new() -> Self {
+        SparseMerkleTree::default()
+    }
+
+    /// Insert a value at a key returning the hash of the value that was replaced.
+    /// Rehashes the nodes on the path of the key
+    pub fn insert(&mut self, key: Blake3Hash, value: &[u8]) -> Option<Blake3Hash> {
+        let replaced = self.leaves.insert(key, Blake3Hash::hash(value));
+        self.update_path(&key);
+
+        replaced
+    }
+
+    /// Remove the value at a key returning its hash.
+    /// Rehashes the nodes on the path of the key
+    pub fn remove(&mut self, key: &Blake3Hash) -> Option<Blake3Hash> {
+        let removed = self.leaves.remove(key);
+        if removed.is_some() {
+            self.update_path(key);
+        }
+
+        removed
+    }
+
+    /// Get the hash of the value at a key
+    pub fn get(&self, key: &Blake3Hash) -> Option<&Blake3Hash> {
+        self.leaves.get(key)
+    }
+
+    /// The number of keys in the tree
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Check whether the tree has no keys
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Hash a leaf as `Blake3(0x00 || key || value_hash)`
+    pub fn hash_leaf(key: &Blake3Hash, value_hash: &Blake3Hash) -> Blake3Hash {
+        MerkleTree::hash_leaf(&[key.0, value_hash.0].concat())
+    }
+
+    /// The root of the tree. The root of a tree without keys is `Blake3Hash::default()`.
+    /// Reads the cached hash of the root without hashing
+    pub fn root(&self) -> Blake3Hash {
+        self.subtree_hash(0, &Blake3Hash::default())
+    }
+
+    /// Create a proof of the value at a key if the key is in the tree,
+    /// otherwise a proof that the key is not in the tree.
+    /// Reads the cached hashes of the siblings so only the leaf hashes
+    /// of siblings with a single leaf are computed
+    pub fn proof(&self, key: &Blake3Hash) -> SparseMerkleProof {
+        let mut depth = 0usize;
+        let mut siblings = Vec::new();
+
+        while self
+            .nodes
+            .contains_key(&(depth, SparseMerkleTree::prefix(key, depth)))
+        {
+            let mut sibling = SparseMerkleTree::prefix(key, depth + 1);
+            SparseMerkleTree::flip_bit(&mut sibling, depth);
+            siblings.push(self.subtree_hash(depth + 1, &sibling));
+            depth += 1;
+        }
+
+        SparseMerkleProof {
+            siblings,
+            leaf: self
+                .subtree_leaves(depth, key)
+                .next()
+                .map(|(key, value_hash)| SparseMerkleLeaf {
+                    key: *key,
+                    value_hash: *value_hash,
+                }),
+        }
+    }
+
+    // Rehash the subtrees with at least two leaves on the path of the key from the bottom up
+    // and drop the cached hashes of the subtrees on the path that no longer have two leaves
+    fn update_path(&mut self, key: &Blake3Hash) {
+        let mut depth = 0usize;
+        while depth < SPARSE_MERKLE_TREE_DEPTH && self.subtree_leaves(depth, key).nth(1).is_some() {
+            depth += 1;
+        }
+
+        for stale_depth in depth..SPARSE_MERKLE_TREE_DEPTH {
+            if self
+                .nodes
+                .remove(&(stale_depth, SparseMerkleTree::prefix(key, stale_depth)))
+                .is_none()
+            {
+                break;
+            }
+        }
+
+        for node_depth in (0..depth).rev() {
+            let left = SparseMerkleTree::prefix(key, node_depth);
+            let mut right = left;
+            SparseMerkleTree::flip_bit(&mut right, node_depth);
+
+            let hash = MerkleTree::hash_node(
+                &self.subtree_hash(node_depth + 1, &left),
+                &self.subtree_hash(node_depth + 1, &right),
+            );
+            self.nodes.insert((node_depth, left), hash);
+        }
+    }
+
+    // The hash of the subtree at `depth` on the path of `path`, either the cached hash
+    // of a subtree with at least two leaves, the hash of a single leaf or the empty hash
+    fn subtree_hash(&self, depth: usize, path: &Blake3Hash) -> Blake3Hash {
+        let prefix = SparseMerkleTree::prefix(path, depth);
+
+        match self.nodes.get(&(depth, prefix)) {
+            Some(hash) => *hash,
+            None => match self.subtree_leaves(depth, path).next() {
+                Some((key, value_hash)) => SparseMerkleTree::hash_leaf(key, value_hash),
+                None => Blake3Hash::default(),
+            },
+        }
+    }
+
+    // The leaves whose keys share the first `depth` bits of `path`
+    fn subtree_leaves(
+        &self,
+        depth: usize,
+        path: &Blake3Hash,
+    ) -> impl Iterator<Item = (&Blake3Hash, &Blake3Hash)> {
+        let first = SparseMerkleTree::prefix(path, depth);
+        let mut last = first;
+        if depth < SPARSE_MERKLE_TREE_DEPTH {
+            last.0[depth / 8] |= 0xff >> (depth % 8);
+            last.0[depth / 8 + 1..].fill(0xff);
+        }
+
+        self.leaves.range(first..=last)
+    }
+
+    // The first `depth` bits of the key with the remaining bits set to zero
+    fn prefix(key: &Blake3Hash, depth: usize) -> Blake3Hash {
+        let mut prefix = *key;
+        if depth < SPARSE_MERKLE_TREE_DEPTH {
+            prefix.0[depth / 8] &= !(0xff >> (depth % 8));
+            prefix.0[depth / 8 + 1..].fill(0);
+        }
+
+        prefix
+    }
+
+    fn flip_bit(key: &mut Blake3Hash, depth: usize) {
+        key.0[depth / 8] ^= 1 << (7 - depth % 8);
+    }
+
+    fn bit(key: &Blake3Hash, depth: usize) -> bool {
+        (key.0[depth / 8] >> (7 - depth % 8)) & 1 == 1
+    }
+}
+
+/// The leaf found at the end of the path of a key in a `SparseMerkleTree`
+#[derive(Clone, Copy, PartialEq, Eq, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct SparseMerkleLeaf {
+    /// The key of the leaf
+    pub key: Blake3Hash,
+    /// The Blake3 hash of the value of the leaf
+    pub value_hash: Blake3Hash,
+}
+
+/// A proof that a key has a value in a `SparseMerkleTree` or that the key is not in the tree
+#[derive(Clone, PartialEq, Eq, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "hex", derive(Debug))]
+pub struct SparseMerkleProof {
+    /// The hashes of the siblings from the child of the root down to the end of the path of the key
+    pub siblings: Vec<Blake3Hash>,
+    /// The leaf at the end of the path of the key which is `None` if the subtree is empty
+    pub leaf: Option<SparseMerkleLeaf>,
+}
+
+impl SparseMerkleProof {
+    /// Verify that a key has the given value in the tree with the given root
+    pub fn verify_membership(
+        &self,
+        root: &Blake3Hash,
+        key: &Blake3Hash,
+        value: &[u8],
+    ) -> UtilitiesResult<()> {
+        match self.leaf {
+            Some(leaf) if &leaf.key == key && leaf.value_hash == Blake3Hash::hash(value) => {
+                self.verify_path(root, key)
+            }
+            _ => Err(UtilitiesError::InvalidMerkleProof),
+        }
+    }
+
+    /// Verify that a key is not in the tree with the given root. The path of the key
+    /// either ends in an empty subtree or in the leaf of a different key
+    /// sharing the same path prefix
+    pub fn verify_non_membership(
+        &self,
+        root: &Blake3Hash,
+        key: &Blake3Hash,
+    ) -> UtilitiesResult<()> {
+        match self.leaf {
+            None => self.verify_path(root, key),
+            Some(leaf)
+                if &leaf.key != key
+                    && (0..self.siblings.len()).all(|depth| {
+                        SparseMerkleTree::bit(&leaf.key, depth) == SparseMerkleTree::bit(key, depth)
+                    }) =>
+            {
+                self.verify_path(root, key)
+            }
+            _ => Err(UtilitiesError::InvalidMerkleProof),
+        }
+    }
+
+    fn verify_path(&self, root: &Blake3Hash, key: &Blake3Hash) -> UtilitiesResult<()> {
+        if self.siblings.len() > SPARSE_MERKLE_TREE_DEPTH {
+            return Err(UtilitiesError::InvalidMerkleProof);
+        }
+
+        let mut hash = match self.leaf {
+            Some(leaf) => SparseMerkleTree::hash_leaf(&leaf.key, &leaf.value_hash),
+            None => Blake3Hash::default(),
+        };
+
+        for (depth, sibling) in self.siblings.iter().enumerate().rev() {
+            hash = if SparseMerkleTree::bit(key, depth) {
+                MerkleTree::hash_node(sibling, &hash)
+            } else {
+                MerkleTree::hash_node(&hash, sibling)
+            };
+        }
+
+        if &hash == root {
+            Ok(())
+        } else {
+            Err(UtilitiesError::InvalidMerkleProof)
+        }
+    }
+}
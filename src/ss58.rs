@@ -0,0 +1,124 @@
+use crate::{Ed25519Public, Sr25519Public, Utilities, UtilitiesError, UtilitiesResult};
+use blake2::{Blake2b512, Digest};
+
+/// The context prepended to the address bytes before hashing to compute the SS58 checksum
+pub const SS58_CHECKSUM_CONTEXT: &[u8] = b"SS58PRE";
+/// The number of bytes of the Blake2b-512 checksum appended to an SS58 address of a 32 byte public key
+pub const SS58_CHECKSUM_LENGTH: usize = 2;
+/// The largest network prefix that can be encoded using the two byte prefix encoding
+pub const SS58_MAX_PREFIX: u16 = 16_383;
+/// The SS58 network prefix of Polkadot, encodes to an address starting with `1`
+pub const SS58_POLKADOT_PREFIX: u16 = 0;
+/// The SS58 network prefix of Kusama, encodes to an address starting with a capital letter
+pub const SS58_KUSAMA_PREFIX: u16 = 2;
+/// The SS58 network prefix for any Substrate chain, encodes to an address starting with `5`
+pub const SS58_GENERIC_SUBSTRATE_PREFIX: u16 = 42;
+/// The names and SS58 network prefixes of well known networks
+pub const SS58_NETWORKS: [(&str, u16); 3] = [
+    ("polkadot", SS58_POLKADOT_PREFIX),
+    ("kusama", SS58_KUSAMA_PREFIX),
+    ("substrate", SS58_GENERIC_SUBSTRATE_PREFIX),
+];
+
+impl Utilities {
+    /// Get the SS58 network prefix of a well known network by its name, for example `"polkadot"`
+    pub fn ss58_network_prefix(name: &str) -> Option<u16> {
+        SS58_NETWORKS
+            .iter()
+            .find(|(network, _)| *network == name)
+            .map(|(_, prefix)| *prefix)
+    }
+
+    /// Get the name of a well known network by its SS58 network prefix
+    pub fn ss58_network_name(prefix: u16) -> Option<&'static str> {
+        SS58_NETWORKS
+            .iter()
+            .find(|(_, network_prefix)| *network_prefix == prefix)
+            .map(|(name, _)| *name)
+    }
+
+    // Prefixes below 64 use one byte and prefixes up to 16383 use two bytes
+    fn ss58_encode(prefix: u16, public_key: &[u8; 32]) -> UtilitiesResult<String> {
+        let mut address = match prefix {
+            0..=63 => vec![prefix as u8],
+            64..=SS58_MAX_PREFIX => vec![
+                ((prefix & 0b0000_0000_1111_1100) >> 2) as u8 | 0b0100_0000,
+                (prefix >> 8) as u8 | ((prefix & 0b0000_0000_0000_0011) << 6) as u8,
+            ],
+            _ => return Err(UtilitiesError::Ss58InvalidPrefix),
+        };
+        address.extend_from_slice(public_key);
+        let checksum = Utilities::ss58_checksum(&address);
+        address.extend_from_slice(&checksum);
+
+        Ok(bs58::encode(address).into_string())
+    }
+
+    fn ss58_decode(value: &str) -> UtilitiesResult<(u16, [u8; 32])> {
+        let address = Utilities::base58_to_bytes(value)?;
+
+        let (prefix, prefix_length) = match address.first() {
+            Some(first @ 0..=63) => (*first as u16, 1),
+            Some(first @ 64..=127) if address.len() > 1 => {
+                let lower = (first << 2) | (address[1] >> 6);
+                let upper = address[1] & 0b0011_1111;
+                ((lower as u16) | ((upper as u16) << 8), 2)
+            }
+            _ => return Err(UtilitiesError::Ss58InvalidPrefix),
+        };
+
+        let expected_length = prefix_length + 32 + SS58_CHECKSUM_LENGTH;
+        if address.len() != expected_length {
+            return Err(UtilitiesError::InvalidLength {
+                expected: expected_length,
+                actual: address.len(),
+            });
+        }
+
+        let (payload, checksum) = address.split_at(address.len() - SS58_CHECKSUM_LENGTH);
+        if Utilities::ss58_checksum(payload) != checksum {
+            return Err(UtilitiesError::Ss58InvalidChecksum);
+        }
+
+        Ok((prefix, Utilities::to_byte_array(&payload[prefix_length..])?))
+    }
+
+    fn ss58_checksum(payload: &[u8]) -> [u8; SS58_CHECKSUM_LENGTH] {
+        let hash = Blake2b512::new()
+            .chain_update(SS58_CHECKSUM_CONTEXT)
+            .chain_update(payload)
+            .finalize();
+
+        [hash[0], hash[1]]
+    }
+}
+
+impl Sr25519Public {
+    /// Return the SS58 address of the public key for the network with the given prefix.
+    /// Returns `UtilitiesError::Ss58InvalidPrefix` if the prefix is greater than 16383
+    pub fn to_ss58(&self, network_prefix: u16) -> UtilitiesResult<String> {
+        Utilities::ss58_encode(network_prefix, &self.0)
+    }
+
+    /// Decode an SS58 address returning the network prefix and the public key
+    pub fn from_ss58(value: &str) -> UtilitiesResult<(u16, Self)> {
+        let (network_prefix, public_key) = Utilities::ss58_decode(value)?;
+
+        Ok((network_prefix, Sr25519Public(public_key)))
+    }
+}
+
+impl Ed25519Public {
+    /// Return the SS58 address of the public key for the network with the given prefix.
+    /// Returns `UtilitiesError::Ss58InvalidPrefix` if the prefix is greater than 16383
+    pub fn to_ss58(&self, network_prefix: u16) -> UtilitiesResult<String> {
+        Utilities::ss58_encode(network_prefix, &self.0)
+    }
+
+    /// Decode an SS58 address returning the network prefix and the public key
+    pub fn from_ss58(value: &str) -> UtilitiesResult<(u16, Self)> {
+        let (network_prefix, public_key) = Utilities::ss58_decode(value)?;
+
+        Ok((network_prefix, Ed25519Public(public_key)))
+    }
+}
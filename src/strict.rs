@@ -0,0 +1,178 @@
+use crate::{
+    AeadNonce, AeadTag, AeadXNonce, Blake3Hash, Ed25519Public, Ed25519Signature, HexVec,
+    Keccak256Hash, MacTag32, MacTag64, Secret32Bytes, SecretVec, Sha256Hash, Sha3_256Hash,
+    Sha512Hash, Sr25519Public, Sr25519Signature, TaiTimestamp, Threshold, Utilities,
+    UtilitiesError, UtilitiesResult, Vote, VoteOutcome, VoteTally, X25519Public,
+};
+use borsh::BorshDeserialize;
+
+/// The default maximum number of bytes accepted by `Utilities::borsh_decode_strict`
+pub const BORSH_DEFAULT_MAX_INPUT_LENGTH: usize = 1_048_576;
+/// The default maximum length of the `Vec` of a `HexVec` or `SecretVec` and of the
+/// `String` and `Vec` fields of a `UtilitiesError` accepted by `Utilities::borsh_decode_strict`
+pub const BORSH_DEFAULT_MAX_VEC_LENGTH: usize = 65_536;
+
+/// The limits enforced by `Utilities::borsh_decode_strict` before any bytes are decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorshLimits {
+    /// The maximum number of bytes that can be decoded
+    pub max_input_length: usize,
+    /// The maximum length of the `Vec` of a `HexVec` or `SecretVec`
+    /// and of the `String` and `Vec` fields of a `UtilitiesError`
+    pub max_vec_length: usize,
+}
+
+impl Default for BorshLimits {
+    fn default() -> Self {
+        BorshLimits {
+            max_input_length: BORSH_DEFAULT_MAX_INPUT_LENGTH,
+            max_vec_length: BORSH_DEFAULT_MAX_VEC_LENGTH,
+        }
+    }
+}
+
+/// The types that can be decoded using `Utilities::borsh_decode_strict`
+pub trait BorshStrict: BorshDeserialize {
+    /// Check the limits against the encoded bytes before they are decoded.
+    /// Only types with a variable length need to check more than the input length
+    fn check_limits(bytes: &[u8], limits: &BorshLimits) -> UtilitiesResult<()> {
+        let _ = (bytes, limits);
+
+        Ok(())
+    }
+
+    /// Check the limits against the decoded value.
+    /// Only types with variable length fields that are not at the start of the bytes need this check
+    fn check_decoded(&self, limits: &BorshLimits) -> UtilitiesResult<()> {
+        let _ = limits;
+
+        Ok(())
+    }
+}
+
+impl Utilities {
+    /// Decode Borsh bytes rejecting inputs longer than `limits.max_input_length`,
+    /// `HexVec` and `SecretVec` length prefixes greater than `limits.max_vec_length`
+    /// and bytes left over after the value is decoded, so that every value has exactly one encoding.
+    /// The `String` and `Vec` fields of a `UtilitiesError` are checked against `limits.max_vec_length`
+    /// once it is decoded. Since some `UtilitiesError` variants only exist when their feature is enabled,
+    /// the discriminants and therefore the encoding of a `UtilitiesError` are only canonical
+    /// between builds with the same features
+    pub fn borsh_decode_strict<T: BorshStrict>(
+        bytes: &[u8],
+        limits: BorshLimits,
+    ) -> UtilitiesResult<T> {
+        if bytes.len() > limits.max_input_length {
+            return Err(UtilitiesError::BorshInputTooLong {
+                max: limits.max_input_length,
+                actual: bytes.len(),
+            });
+        }
+        T::check_limits(bytes, &limits)?;
+
+        let mut buffer = bytes;
+        let value = match T::deserialize(&mut buffer) {
+            Ok(value) => value,
+            Err(_) => return Err(UtilitiesError::BorshInvalidData),
+        };
+
+        if !buffer.is_empty() {
+            return Err(UtilitiesError::BorshTrailingBytes {
+                count: buffer.len(),
+            });
+        }
+        value.check_decoded(&limits)?;
+
+        Ok(value)
+    }
+
+    // Check the `u32` little endian length prefix of a Borsh encoded `Vec`
+    fn check_vec_length_prefix(bytes: &[u8], limits: &BorshLimits) -> UtilitiesResult<()> {
+        let length = match bytes.get(..4) {
+            Some(prefix) => u32::from_le_bytes(Utilities::to_byte_array(prefix)?) as usize,
+            None => return Err(UtilitiesError::BorshInvalidData),
+        };
+
+        Utilities::check_vec_length(length, limits)
+    }
+
+    // Check the length of a decoded `Vec` or `String`
+    fn check_vec_length(length: usize, limits: &BorshLimits) -> UtilitiesResult<()> {
+        match length > limits.max_vec_length {
+            true => Err(UtilitiesError::BorshVecTooLong {
+                max: limits.max_vec_length,
+                actual: length,
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+impl BorshStrict for HexVec {
+    fn check_limits(bytes: &[u8], limits: &BorshLimits) -> UtilitiesResult<()> {
+        Utilities::check_vec_length_prefix(bytes, limits)
+    }
+}
+
+impl BorshStrict for SecretVec {
+    fn check_limits(bytes: &[u8], limits: &BorshLimits) -> UtilitiesResult<()> {
+        Utilities::check_vec_length_prefix(bytes, limits)
+    }
+}
+
+// The `String` and `Vec` fields are checked after decoding since their length prefixes
+// follow the discriminant of the variant
+impl BorshStrict for UtilitiesError {
+    fn check_decoded(&self, limits: &BorshLimits) -> UtilitiesResult<()> {
+        let length = match self {
+            UtilitiesError::HexInvalidHexCharacter { c: value, .. }
+            | UtilitiesError::Base58InvalidCharacter {
+                character: value, ..
+            }
+            | UtilitiesError::Bech32InvalidCharacter { character: value }
+            | UtilitiesError::MultibaseUnsupportedPrefix { prefix: value }
+            | UtilitiesError::JwkUnsupportedKeyType { key_type: value }
+            | UtilitiesError::JwkUnsupportedCurve { curve: value }
+            | UtilitiesError::PemUnexpectedLabel { label: value }
+            | UtilitiesError::KeyAlgorithmMismatch { oid: value }
+            | UtilitiesError::Pkcs8UnsupportedEncryption { oid: value } => value.len(),
+            UtilitiesError::InvalidSignaturesInBatch { indices } => indices.len(),
+            _ => 0,
+        };
+
+        Utilities::check_vec_length(length, limits)
+    }
+}
+
+/// Implements `BorshStrict` for the types whose only limit is the input length
+macro_rules! impl_borsh_strict {
+    ($($data_type:ident),+ $(,)?) => {
+        $(
+            impl BorshStrict for $data_type {}
+        )+
+    };
+}
+
+impl_borsh_strict!(
+    Blake3Hash,
+    Sha256Hash,
+    Sha512Hash,
+    Sha3_256Hash,
+    Keccak256Hash,
+    TaiTimestamp,
+    Ed25519Public,
+    Ed25519Signature,
+    Sr25519Public,
+    Sr25519Signature,
+    X25519Public,
+    Secret32Bytes,
+    AeadNonce,
+    AeadXNonce,
+    AeadTag,
+    MacTag32,
+    MacTag64,
+    VoteTally,
+    Vote,
+    VoteOutcome,
+    Threshold,
+);
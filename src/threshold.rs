@@ -2,6 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 
 /// The total number of items or members required from a list in order to execute or approve a certain task
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Threshold {
     /// All the items or members of a list are required to execute or approve a task
     All,
@@ -0,0 +1,168 @@
+use crate::{Secret32Bytes, Utilities, UtilitiesError, UtilitiesResult};
+use core::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "ed25519")]
+use crate::{Ed25519Public, Ed25519Signature};
+#[cfg(feature = "ed25519")]
+use ed25519_dalek::{Keypair as Ed25519Keypair, PublicKey, SecretKey, Signer};
+
+#[cfg(feature = "sr25519_vault")]
+use crate::{Sr25519Public, Sr25519Signature};
+#[cfg(feature = "sr25519_vault")]
+use schnorrkel::{ExpansionMode, Keypair as Sr25519Keypair, MiniSecretKey};
+
+/// A vault that owns an `ed25519_dalek::Keypair` and uses it to sign messages.
+/// The secret key is zeroed when the vault is dropped and
+/// `fmt::Debug` never prints the secret key
+#[cfg(feature = "ed25519")]
+pub struct Ed25519Vault {
+    keypair: Ed25519Keypair,
+}
+
+#[cfg(feature = "ed25519")]
+impl Ed25519Vault {
+    /// Create a vault that takes ownership of an `ed25519_dalek::Keypair`
+    pub fn new(keypair: Ed25519Keypair) -> Self {
+        Ed25519Vault { keypair }
+    }
+
+    /// Create a vault from the 64 bytes of an `ed25519_dalek::Keypair`
+    pub fn from_keypair_bytes(bytes: &[u8]) -> UtilitiesResult<Self> {
+        Ok(Ed25519Vault::new(Utilities::to_ed25519_keypair(bytes)?))
+    }
+
+    /// Create a vault from a 32 byte secret key seed
+    pub fn from_seed(seed: &Secret32Bytes) -> UtilitiesResult<Self> {
+        let secret = match SecretKey::from_bytes(&seed.0) {
+            Ok(secret) => secret,
+            Err(_) => return Err(UtilitiesError::InvalidBytesForSecretKey),
+        };
+        let public = PublicKey::from(&secret);
+
+        Ok(Ed25519Vault::new(Ed25519Keypair { secret, public }))
+    }
+
+    /// Create a vault from a random 32 byte secret key seed generated using `ChaCha20`
+    #[cfg(feature = "random")]
+    pub fn generate() -> Self {
+        let seed = Secret32Bytes(Utilities::rand32_chacha20());
+
+        Ed25519Vault::from_seed(&seed).unwrap() // Never fails since any 32 bytes are a valid secret key
+    }
+
+    /// Get the public key of the keypair stored in the vault
+    pub fn public(&self) -> Ed25519Public {
+        Ed25519Public(self.keypair.public.to_bytes())
+    }
+
+    /// Sign a message with the keypair stored in the vault
+    pub fn sign(&self, message: &[u8]) -> Ed25519Signature {
+        Ed25519Signature(self.keypair.sign(message).to_bytes())
+    }
+
+    // Copy the 32 byte secret key seed for the key export formats
+    #[cfg(any(feature = "jwk", feature = "pkcs8"))]
+    pub(crate) fn secret(&self) -> Secret32Bytes {
+        Secret32Bytes(self.keypair.secret.to_bytes())
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Zeroize for Ed25519Vault {
+    fn zeroize(&mut self) {
+        self.keypair.secret.zeroize();
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Drop for Ed25519Vault {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl ZeroizeOnDrop for Ed25519Vault {}
+
+#[cfg(feature = "ed25519")]
+impl fmt::Debug for Ed25519Vault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ed25519Vault").field(&"[REDACTED]").finish()
+    }
+}
+
+/// A vault that owns a `schnorrkel::Keypair` and uses it to sign messages
+/// under a signing context. The secret key is zeroed when the vault is dropped and
+/// `fmt::Debug` never prints the secret key
+#[cfg(feature = "sr25519_vault")]
+pub struct Sr25519Vault {
+    keypair: Sr25519Keypair,
+}
+
+#[cfg(feature = "sr25519_vault")]
+impl Sr25519Vault {
+    /// Create a vault that takes ownership of a `schnorrkel::Keypair`
+    pub fn new(keypair: Sr25519Keypair) -> Self {
+        Sr25519Vault { keypair }
+    }
+
+    /// Create a vault from the 96 bytes of a `schnorrkel::Keypair`
+    pub fn from_keypair_bytes(bytes: &[u8]) -> UtilitiesResult<Self> {
+        Ok(Sr25519Vault::new(Utilities::to_sr25519_keypair(bytes)?))
+    }
+
+    /// Create a vault from a 32 byte mini secret key seed.
+    /// The seed is expanded using `ExpansionMode::Ed25519` which is the mode used by Substrate
+    pub fn from_seed(seed: &Secret32Bytes) -> UtilitiesResult<Self> {
+        match MiniSecretKey::from_bytes(&seed.0) {
+            Ok(mini_secret) => Ok(Sr25519Vault::new(
+                mini_secret.expand_to_keypair(ExpansionMode::Ed25519),
+            )),
+            Err(_) => Err(UtilitiesError::InvalidBytesForSecretKey),
+        }
+    }
+
+    /// Create a vault from a random 32 byte mini secret key seed generated using `ChaCha20`
+    #[cfg(feature = "random")]
+    pub fn generate() -> Self {
+        let seed = Secret32Bytes(Utilities::rand32_chacha20());
+
+        Sr25519Vault::from_seed(&seed).unwrap() // Never fails since any 32 bytes are a valid mini secret key
+    }
+
+    /// Get the public key of the keypair stored in the vault
+    pub fn public(&self) -> Sr25519Public {
+        Sr25519Public(self.keypair.public.to_bytes())
+    }
+
+    /// Sign a message under a signing context, for example Substrate's `b"substrate"`,
+    /// with the keypair stored in the vault
+    pub fn sign(&self, context: &[u8], message: &[u8]) -> Sr25519Signature {
+        Sr25519Signature(self.keypair.sign_simple(context, message).to_bytes())
+    }
+}
+
+#[cfg(feature = "sr25519_vault")]
+impl Zeroize for Sr25519Vault {
+    fn zeroize(&mut self) {
+        self.keypair.secret.zeroize();
+    }
+}
+
+#[cfg(feature = "sr25519_vault")]
+impl Drop for Sr25519Vault {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "sr25519_vault")]
+impl ZeroizeOnDrop for Sr25519Vault {}
+
+#[cfg(feature = "sr25519_vault")]
+impl fmt::Debug for Sr25519Vault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sr25519Vault").field(&"[REDACTED]").finish()
+    }
+}
@@ -11,6 +11,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 /// }
 /// ```
 #[derive(Debug, Clone, Copy, BorshDeserialize, BorshSerialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoteTally {
     accepted: usize,
     rejected: usize,
@@ -62,6 +63,7 @@ impl Default for VoteTally {
 
 /// An outcome of an election
 #[derive(Debug, Clone, Copy, BorshDeserialize, BorshSerialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VoteOutcome {
     /// The majority accepted the proposal
     Accepted,
@@ -72,16 +74,14 @@ pub enum VoteOutcome {
 }
 
 /// An instruction to either accept or reject a vote
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Vote {
     /// Accept a vote
     Accept,
     /// Reject a vote
+    #[default]
     Reject,
 }
-
-impl Default for Vote {
-    fn default() -> Self {
-        Vote::Reject
-    }
-}
@@ -0,0 +1,66 @@
+use crate::{Secret32Bytes, UtilitiesError, UtilitiesResult, X25519Public};
+use core::fmt;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A representation of a 32 byte X25519 secret key used for Diffie-Hellman key agreement.
+/// The secret key is zeroed when the value is dropped and
+/// `fmt::Debug` never prints the secret key
+pub struct X25519Secret(StaticSecret);
+
+impl X25519Secret {
+    /// Create an X25519 secret key from 32 secret bytes
+    pub fn from_secret(secret: &Secret32Bytes) -> Self {
+        X25519Secret(StaticSecret::from(secret.0))
+    }
+
+    /// Generate a random X25519 secret key using `ChaCha20`
+    #[cfg(feature = "random")]
+    pub fn generate() -> Self {
+        X25519Secret::from_secret(&Secret32Bytes(crate::Utilities::rand32_chacha20()))
+    }
+
+    /// Get the public key of the secret key
+    pub fn public(&self) -> X25519Public {
+        X25519Public(PublicKey::from(&self.0).to_bytes())
+    }
+
+    /// Perform a Diffie-Hellman key agreement with the public key of another party.
+    /// Returns `UtilitiesError::X25519NonContributorySharedSecret` if the shared secret
+    /// is all zeros which happens when the public key is a low order point
+    pub fn diffie_hellman(&self, their_public: &X25519Public) -> UtilitiesResult<Secret32Bytes> {
+        let shared_secret = self.0.diffie_hellman(&PublicKey::from(their_public.0));
+
+        if !shared_secret.was_contributory() {
+            return Err(UtilitiesError::X25519NonContributorySharedSecret);
+        }
+
+        Ok(Secret32Bytes(shared_secret.to_bytes()))
+    }
+
+    // Copy the 32 byte secret key for the key export formats
+    #[cfg(any(feature = "jwk", feature = "pkcs8"))]
+    pub(crate) fn secret(&self) -> Secret32Bytes {
+        Secret32Bytes(self.0.to_bytes())
+    }
+}
+
+impl Zeroize for X25519Secret {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for X25519Secret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for X25519Secret {}
+
+impl fmt::Debug for X25519Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("X25519Secret").field(&"[REDACTED]").finish()
+    }
+}